@@ -1,4 +1,5 @@
 use crate::application::app_error::AppError;
+use crate::application::request_id::RequestId;
 use axum::Json;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
@@ -6,37 +7,64 @@ use serde_json::json;
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        tracing::error!("API Error: {:?}", self);
-
         let status = self.status_code();
         let error_code = self.error_code();
         let message = self.to_string();
 
-        let body = Json(json!({
+        if status.is_server_error() {
+            tracing::error!(
+                status = status.as_u16(),
+                error_code,
+                error = %message,
+                "API error"
+            );
+        } else {
+            tracing::warn!(
+                status = status.as_u16(),
+                error_code,
+                error = %message,
+                "API error"
+            );
+        }
+
+        let mut body = json!({
             "error": {
                 "code": error_code,
                 "message": message,
             }
-        }));
+        });
+        if let Some(id) = RequestId::current() {
+            body["error"]["request_id"] = json!(id.as_str());
+        }
+        if let AppError::NotFoundWithSuggestions { suggestions, .. } = &self {
+            body["error"]["suggestions"] = json!(suggestions);
+        }
+        let body = Json(body);
 
         (status, body).into_response()
     }
 }
 
 impl AppError {
-    fn status_code(&self) -> StatusCode {
+    pub(crate) fn status_code(&self) -> StatusCode {
         match self {
-            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::NotFound(_) | AppError::NotFoundWithSuggestions { .. } => {
+                StatusCode::NOT_FOUND
+            }
             AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
             AppError::GenericError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
-    fn error_code(&self) -> &'static str {
+    pub(crate) fn error_code(&self) -> &'static str {
         match self {
-            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::NotFound(_) | AppError::NotFoundWithSuggestions { .. } => "NOT_FOUND",
             AppError::BadRequest(_) => "BAD_REQUEST",
             AppError::GenericError(_) => "INTERNAL_ERROR",
+            AppError::RateLimited(_) => "RATE_LIMITED",
+            AppError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
         }
     }
 }
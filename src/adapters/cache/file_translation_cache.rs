@@ -0,0 +1,442 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use moka::Expiry;
+use moka::future::Cache;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use crate::adapters::cache::translation_cache::{
+    CachedTranslation, TranslationCache, TranslationInit,
+};
+use crate::adapters::cache::typed_cache::{Lookup, log_lookup};
+use crate::adapters::metrics::CacheMetrics;
+use crate::application::app_error::{AppError, AppResult};
+
+/// The file is compacted once it holds this many lines per live entry...
+const COMPACTION_RATIO: u64 = 2;
+/// ...and at least this many, so that small caches aren't rewritten on every
+/// few inserts.
+const MIN_COMPACTION_LINES: u64 = 256;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredTranslation {
+    key: String,
+    #[serde(flatten)]
+    entry: CachedTranslation,
+}
+
+struct CachedTranslationExpiry {
+    ttl: Duration,
+}
+
+impl Expiry<String, CachedTranslation> for CachedTranslationExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        value: &CachedTranslation,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(self.ttl.saturating_sub(value.age()))
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        value: &CachedTranslation,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(self.ttl.saturating_sub(value.age()))
+    }
+}
+
+/// Translation cache persisted to a JSON Lines file.
+///
+/// Entries are loaded into memory at startup and every insert is appended to
+/// the file (write-through). The TTL is measured from when an entry was
+/// stored, so restarting the server does not extend its lifetime. Expired and
+/// superseded lines are dropped when the file is compacted: on startup, and
+/// whenever it grows to `COMPACTION_RATIO` lines per live entry.
+pub struct FileTranslationCache {
+    entries: Cache<String, CachedTranslation>,
+    path: PathBuf,
+    ttl: Duration,
+    file_lock: Mutex<()>,
+    /// Lines in the file since it was last compacted.
+    lines: AtomicU64,
+    metrics: Option<CacheMetrics>,
+}
+
+impl FileTranslationCache {
+    pub async fn open(
+        path: impl AsRef<Path>,
+        max_capacity: u64,
+        ttl: Duration,
+        metrics: Option<CacheMetrics>,
+    ) -> io::Result<Self> {
+        let mut entries = Cache::builder()
+            .max_capacity(max_capacity)
+            .expire_after(CachedTranslationExpiry { ttl });
+        if let Some(metrics) = metrics.clone() {
+            entries =
+                entries.eviction_listener(move |_key, _value, cause| metrics.record_removal(cause));
+        }
+
+        let cache = Self {
+            entries: entries.build(),
+            path: path.as_ref().to_path_buf(),
+            ttl,
+            file_lock: Mutex::new(()),
+            lines: AtomicU64::new(0),
+            metrics,
+        };
+
+        cache.load().await?;
+
+        Ok(cache)
+    }
+
+    async fn load(&self) -> io::Result<()> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<StoredTranslation>(line) {
+                Ok(stored) if stored.entry.age() < self.ttl => {
+                    self.entries.insert(stored.key, stored.entry).await;
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Skipping corrupt translation cache line: {}", e),
+            }
+        }
+
+        self.entries.run_pending_tasks().await;
+        tracing::info!(
+            "Loaded {} translations from {}",
+            self.entries.entry_count(),
+            self.path.display()
+        );
+
+        self.compact().await
+    }
+
+    async fn compact(&self) -> io::Result<()> {
+        let _guard = self.file_lock.lock().await;
+
+        let mut contents = String::new();
+        let mut lines = 0;
+        for (key, entry) in self.entries.iter() {
+            let stored = StoredTranslation {
+                key: key.as_ref().clone(),
+                entry,
+            };
+            contents.push_str(&serde_json::to_string(&stored)?);
+            contents.push('\n');
+            lines += 1;
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, contents).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+        self.lines.store(lines, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Compacts the file once superseded and expired lines outnumber live
+    /// ones.
+    async fn compact_if_grown(&self) {
+        self.entries.run_pending_tasks().await;
+        let threshold = (self.entries.entry_count() * COMPACTION_RATIO).max(MIN_COMPACTION_LINES);
+        if self.lines.load(Ordering::Relaxed) < threshold {
+            return;
+        }
+
+        if let Err(e) = self.compact().await {
+            tracing::error!(
+                "Failed to compact translation cache {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+
+    /// Appends `stored` to the file. Callers hold `file_lock` and have already
+    /// put the entry in `entries`, so a concurrent compaction either snapshots
+    /// the entry or finishes before the line is written.
+    async fn persist(&self, stored: &StoredTranslation) {
+        if let Err(e) = self.append(stored).await {
+            tracing::error!(
+                "Failed to persist translation to {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+
+    async fn append(&self, stored: &StoredTranslation) -> io::Result<()> {
+        let mut line = serde_json::to_string(stored)?;
+        line.push('\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        self.lines.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TranslationCache for FileTranslationCache {
+    async fn get_or_try_insert_with<'a>(
+        &'a self,
+        key: String,
+        init: TranslationInit<'a>,
+    ) -> AppResult<Lookup<CachedTranslation>> {
+        let entry = self
+            .entries
+            .entry(key.clone())
+            .or_try_insert_with(async { Ok::<_, AppError>(CachedTranslation::new(init.await?)) })
+            .await
+            .map_err(|e| (*e).clone());
+        if let Some(metrics) = &self.metrics {
+            let hit = entry.as_ref().is_ok_and(|entry| !entry.is_fresh());
+            metrics.record_lookup(hit);
+            log_lookup(metrics, hit);
+        }
+        let entry = entry?;
+        if entry.is_fresh() {
+            let stored = StoredTranslation {
+                key,
+                entry: entry.value().clone(),
+            };
+            let guard = self.file_lock.lock().await;
+            self.persist(&stored).await;
+            drop(guard);
+            self.compact_if_grown().await;
+        }
+
+        Ok(Lookup {
+            from_cache: !entry.is_fresh(),
+            value: entry.into_value(),
+        })
+    }
+
+    async fn insert(&self, key: String, translation: String) {
+        let stored = StoredTranslation {
+            key,
+            entry: CachedTranslation::new(translation),
+        };
+
+        let guard = self.file_lock.lock().await;
+        self.entries
+            .insert(stored.key.clone(), stored.entry.clone())
+            .await;
+        self.persist(&stored).await;
+        drop(guard);
+        self.compact_if_grown().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::cache::translation_cache::unix_now;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+
+    async fn cached(cache: &FileTranslationCache, key: &str) -> Option<String> {
+        cache
+            .get_or_try_insert_with(
+                key.to_string(),
+                Box::pin(async { Err(AppError::NotFound(String::new())) }),
+            )
+            .await
+            .ok()
+            .map(|lookup| lookup.value.translation)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "simple_pokedex_{}_{}.jsonl",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn test_translations_survive_reopen() {
+        let path = temp_path("survive_reopen");
+
+        let cache = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+        cache
+            .get_or_try_insert_with(
+                "yoda_text".to_string(),
+                Box::pin(async { Ok("Text, this is".to_string()) }),
+            )
+            .await
+            .unwrap();
+        drop(cache);
+
+        let reopened = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cached(&reopened, "yoda_text").await,
+            Some("Text, this is".to_string())
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_file_is_compacted_once_it_outgrows_the_entries() {
+        let path = temp_path("compacted_while_running");
+        let cache = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+
+        for i in 0..MIN_COMPACTION_LINES + 10 {
+            cache
+                .insert("yoda_text".to_string(), format!("Text {}, this is", i))
+                .await;
+        }
+
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count() as u64;
+        assert!(lines < MIN_COMPACTION_LINES, "{} lines", lines);
+
+        drop(cache);
+        let reopened = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            cached(&reopened, "yoda_text").await,
+            Some(format!("Text {}, this is", MIN_COMPACTION_LINES + 9))
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_inserts_racing_a_compaction_are_kept() {
+        let path = temp_path("racing_compaction");
+        let cache = Arc::new(
+            FileTranslationCache::open(&path, 10_000, Duration::from_secs(60), None)
+                .await
+                .unwrap(),
+        );
+
+        let done = Arc::new(AtomicBool::new(false));
+        let compactor = {
+            let cache = cache.clone();
+            let done = done.clone();
+            tokio::spawn(async move {
+                while !done.load(Ordering::SeqCst) {
+                    cache.compact().await.unwrap();
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        // Once an insert returns, its line must be in the file whatever the
+        // compactions running around it did.
+        let inserts: Vec<_> = (0..1000)
+            .map(|i| {
+                let cache = cache.clone();
+                let path = path.clone();
+                tokio::spawn(async move {
+                    let key = format!("yoda_{}", i);
+                    if i % 2 == 0 {
+                        cache.insert(key.clone(), "Text, this is".to_string()).await;
+                    } else {
+                        cache
+                            .get_or_try_insert_with(
+                                key.clone(),
+                                Box::pin(async { Ok("Text, this is".to_string()) }),
+                            )
+                            .await
+                            .unwrap();
+                    }
+                    let contents = tokio::fs::read_to_string(&path).await.unwrap();
+                    contents.contains(&format!("\"key\":\"{}\"", key))
+                })
+            })
+            .collect();
+        let mut lost = Vec::new();
+        for (i, insert) in inserts.into_iter().enumerate() {
+            if !insert.await.unwrap() {
+                lost.push(i);
+            }
+        }
+        done.store(true, Ordering::SeqCst);
+        compactor.await.unwrap();
+
+        assert!(lost.is_empty(), "lost {:?}", lost);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_not_loaded() {
+        let path = temp_path("expired_entries");
+        let stale = StoredTranslation {
+            key: "yoda_old".to_string(),
+            entry: CachedTranslation {
+                translation: "Old, this is".to_string(),
+                stored_at: unix_now() - 120,
+            },
+        };
+        std::fs::write(&path, serde_json::to_string(&stale).unwrap() + "\n").unwrap();
+
+        let cache = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+
+        assert_eq!(cached(&cache, "yoda_old").await, None);
+        assert!(std::fs::read_to_string(&path).unwrap().is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_lines_are_skipped() {
+        let path = temp_path("corrupt_lines");
+        let valid = StoredTranslation {
+            key: "yoda_ok".to_string(),
+            entry: CachedTranslation::new("Fine, this is".to_string()),
+        };
+        std::fs::write(
+            &path,
+            format!("not json\n{}\n", serde_json::to_string(&valid).unwrap()),
+        )
+        .unwrap();
+
+        let cache = FileTranslationCache::open(&path, 100, Duration::from_secs(60), None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cached(&cache, "yoda_ok").await,
+            Some("Fine, this is".to_string())
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
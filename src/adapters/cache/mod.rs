@@ -1 +1,3 @@
+pub mod file_translation_cache;
 pub mod translation_cache;
+pub mod typed_cache;
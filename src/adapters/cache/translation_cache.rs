@@ -1,27 +1,127 @@
-use moka::future::Cache;
-use std::time::Duration;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-#[allow(dead_code)]
-#[derive(Clone)]
-pub struct TranslationCache {
-    cache: Cache<String, String>,
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::cache::typed_cache::{Lookup, TypedCache};
+use crate::application::app_error::AppResult;
+
+pub type TranslationInit<'a> = Pin<Box<dyn Future<Output = AppResult<String>> + Send + 'a>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedTranslation {
+    pub translation: String,
+    pub stored_at: u64,
 }
 
-impl TranslationCache {
-    pub fn new(ttl_seconds: u64) -> Self {
-        let cache = Cache::builder()
-            .max_capacity(1000)
-            .time_to_live(Duration::from_secs(ttl_seconds))
-            .build();
+impl CachedTranslation {
+    pub fn new(translation: String) -> Self {
+        Self {
+            translation,
+            stored_at: unix_now(),
+        }
+    }
 
-        Self { cache }
+    pub fn age(&self) -> Duration {
+        Duration::from_secs(unix_now().saturating_sub(self.stored_at))
     }
+}
+
+#[async_trait]
+pub trait TranslationCache: Send + Sync {
+    /// Returns the cached translation for `key`, or resolves `init` and caches
+    /// its result. Concurrent callers for the same key share one `init`.
+    async fn get_or_try_insert_with<'a>(
+        &'a self,
+        key: String,
+        init: TranslationInit<'a>,
+    ) -> AppResult<Lookup<CachedTranslation>>;
 
-    pub async fn get(&self, key: &str) -> Option<String> {
-        self.cache.get(key).await
+    /// Stores a translation, replacing any existing entry and resetting its age.
+    async fn insert(&self, key: String, translation: String);
+}
+
+#[async_trait]
+impl TranslationCache for TypedCache<String, CachedTranslation> {
+    async fn get_or_try_insert_with<'a>(
+        &'a self,
+        key: String,
+        init: TranslationInit<'a>,
+    ) -> AppResult<Lookup<CachedTranslation>> {
+        TypedCache::get_or_try_insert_with(self, key, async {
+            init.await.map(CachedTranslation::new)
+        })
+        .await
+        .map_err(|e| (*e).clone())
     }
 
-    pub async fn insert(&self, key: String, value: String) {
-        self.cache.insert(key, value).await
+    async fn insert(&self, key: String, translation: String) {
+        TypedCache::insert(self, key, CachedTranslation::new(translation)).await
+    }
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_concurrent_misses_share_a_single_translation() {
+        let cache: Arc<dyn TranslationCache> = Arc::new(
+            TypedCache::<String, CachedTranslation>::new(100, Duration::from_secs(60), None),
+        );
+        let upstream_calls = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..50)
+            .map(|_| {
+                let cache = cache.clone();
+                let upstream_calls = upstream_calls.clone();
+
+                tokio::spawn(async move {
+                    cache
+                        .get_or_try_insert_with(
+                            "yoda_text".to_string(),
+                            Box::pin(async move {
+                                upstream_calls.fetch_add(1, Ordering::SeqCst);
+                                tokio::time::sleep(Duration::from_millis(50)).await;
+                                Ok("Text, this is".to_string())
+                            }),
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        let mut fresh = 0;
+        for handle in handles {
+            let lookup = handle.await.unwrap().unwrap();
+            assert_eq!(lookup.value.translation, "Text, this is");
+            fresh += usize::from(!lookup.from_cache);
+        }
+
+        assert_eq!(upstream_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(fresh, 1);
+    }
+
+    #[test]
+    fn test_cached_translation_age() {
+        let fresh = CachedTranslation::new("Text, this is".to_string());
+        let old = CachedTranslation {
+            translation: "Text, this is".to_string(),
+            stored_at: unix_now() - 90,
+        };
+
+        assert!(fresh.age() < Duration::from_secs(5));
+        assert!(old.age() >= Duration::from_secs(90));
     }
 }
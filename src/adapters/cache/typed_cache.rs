@@ -0,0 +1,101 @@
+use moka::future::Cache;
+use std::borrow::Borrow;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::adapters::metrics::CacheMetrics;
+
+/// A value read through a cache, flagged with whether it was already cached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lookup<V> {
+    pub value: V,
+    pub from_cache: bool,
+}
+
+#[derive(Clone)]
+pub struct TypedCache<K, V> {
+    cache: Cache<K, V>,
+    metrics: Option<CacheMetrics>,
+}
+
+impl<K, V> TypedCache<K, V>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    /// With `metrics`, hits, misses and evictions are recorded.
+    pub fn new(max_capacity: u64, ttl: Duration, metrics: Option<CacheMetrics>) -> Self {
+        let mut cache = Cache::builder()
+            .max_capacity(max_capacity)
+            .time_to_live(ttl);
+        if let Some(metrics) = metrics.clone() {
+            cache =
+                cache.eviction_listener(move |_key, _value, cause| metrics.record_removal(cause));
+        }
+
+        Self {
+            cache: cache.build(),
+            metrics,
+        }
+    }
+
+    pub async fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let value = self.cache.get(key).await;
+        self.record_lookup(value.is_some());
+        value
+    }
+
+    pub async fn insert(&self, key: K, value: V) {
+        self.cache.insert(key, value).await
+    }
+
+    /// Returns the cached value for `key`, or resolves `init` and caches its
+    /// result. Concurrent callers for the same missing key await a single
+    /// `init` future; errors are shared with them but never cached.
+    pub async fn get_or_try_insert_with<F, E>(&self, key: K, init: F) -> Result<Lookup<V>, Arc<E>>
+    where
+        F: Future<Output = Result<V, E>>,
+        E: Send + Sync + 'static,
+    {
+        let entry = self
+            .cache
+            .entry(key)
+            .or_try_insert_with(init)
+            .await
+            .inspect_err(|_| self.record_read_through(false))?;
+        self.record_read_through(!entry.is_fresh());
+
+        Ok(Lookup {
+            from_cache: !entry.is_fresh(),
+            value: entry.into_value(),
+        })
+    }
+
+    fn record_lookup(&self, hit: bool) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_lookup(hit);
+        }
+    }
+
+    fn record_read_through(&self, hit: bool) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_lookup(hit);
+            log_lookup(metrics, hit);
+        }
+    }
+}
+
+/// Logs a read-through lookup under the name its metrics are recorded with.
+pub(crate) fn log_lookup(metrics: &CacheMetrics, hit: bool) {
+    tracing::info!(
+        cache = metrics.name(),
+        cache_status = if hit { "hit" } else { "miss" },
+        "Cache lookup"
+    );
+}
@@ -0,0 +1,242 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half-open",
+        }
+    }
+}
+
+struct Inner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+/// Circuit breaker guarding a single upstream.
+///
+/// Opens after `failure_threshold` consecutive failures and rejects calls
+/// until the reset window elapses. The window is the upstream's own hint
+/// (e.g. `Retry-After`) when one is given, `open_duration` otherwise. Once the
+/// window elapses a single probe is let through (half-open): success closes
+/// the circuit, failure opens it again.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                open_until: None,
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        let inner = self.inner.lock().unwrap();
+
+        match (inner.state, inner.open_until) {
+            (CircuitState::Open, Some(until)) if Instant::now() >= until => CircuitState::HalfOpen,
+            (state, _) => state,
+        }
+    }
+
+    /// Returns a permit if a call may be attempted. The call's outcome is
+    /// reported through the permit; dropping it unreported (e.g. when the call
+    /// is cancelled) lets another probe through.
+    pub fn try_acquire(&self) -> Option<CircuitPermit<'_>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        let permitted = match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => {
+                let elapsed = inner.open_until.is_none_or(|until| Instant::now() >= until);
+
+                if elapsed {
+                    inner.state = CircuitState::HalfOpen;
+                    inner.probe_in_flight = true;
+                }
+
+                elapsed
+            }
+            CircuitState::HalfOpen if inner.probe_in_flight => false,
+            CircuitState::HalfOpen => {
+                inner.probe_in_flight = true;
+                true
+            }
+        };
+
+        // Rejected calls get no permit, as dropping one releases the probe.
+        if permitted {
+            Some(CircuitPermit {
+                breaker: self,
+                reported: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.state != CircuitState::Closed {
+            tracing::info!("Circuit breaker closed");
+        }
+
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.open_until = None;
+        inner.probe_in_flight = false;
+    }
+
+    fn record_failure(&self, reset_after: Option<Duration>) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.consecutive_failures += 1;
+        inner.probe_in_flight = false;
+
+        if inner.state == CircuitState::HalfOpen
+            || inner.consecutive_failures >= self.failure_threshold
+        {
+            let window = reset_after.unwrap_or(self.open_duration);
+
+            tracing::warn!(
+                "Circuit breaker opened after {} consecutive failures, retrying in {:?}",
+                inner.consecutive_failures,
+                window
+            );
+
+            inner.state = CircuitState::Open;
+            inner.open_until = Some(Instant::now() + window);
+        }
+    }
+}
+
+/// A call let through by `CircuitBreaker::try_acquire`.
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    reported: bool,
+}
+
+impl CircuitPermit<'_> {
+    pub fn record_success(mut self) {
+        self.reported = true;
+        self.breaker.record_success();
+    }
+
+    pub fn record_failure(mut self, reset_after: Option<Duration>) {
+        self.reported = true;
+        self.breaker.record_failure(reset_after);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if !self.reported {
+            self.breaker.inner.lock().unwrap().probe_in_flight = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold_failures() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.try_acquire().unwrap().record_failure(None);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.try_acquire().unwrap().record_failure(None);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.try_acquire().unwrap().record_failure(None);
+        breaker.try_acquire().unwrap().record_success();
+        breaker.try_acquire().unwrap().record_failure(None);
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_upstream_reset_window_overrides_default() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(3600));
+
+        breaker.record_failure(Some(Duration::ZERO));
+
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire().is_some());
+    }
+
+    #[test]
+    fn test_half_open_allows_a_single_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+
+        breaker.record_failure(None);
+
+        let _probe = breaker.try_acquire().unwrap();
+        assert!(breaker.try_acquire().is_none());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    }
+
+    #[test]
+    fn test_dropped_probe_lets_another_probe_through() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        breaker.record_failure(None);
+
+        let probe = breaker.try_acquire().unwrap();
+        drop(probe);
+
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker.try_acquire().unwrap().record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_probe_outcome_closes_or_reopens() {
+        let breaker = CircuitBreaker::new(3, Duration::ZERO);
+        for _ in 0..3 {
+            breaker.record_failure(None);
+        }
+
+        breaker
+            .try_acquire()
+            .unwrap()
+            .record_failure(Some(Duration::from_secs(60)));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        breaker.record_failure(None);
+
+        breaker.try_acquire().unwrap().record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
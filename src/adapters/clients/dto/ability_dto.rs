@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AbilityResponseDto {
+    pub name: String,
+    pub effect_entries: Vec<VerboseEffectDto>,
+    pub pokemon: Vec<AbilityPokemonDto>,
+}
+
+/// An effect description in one language, as used by abilities and moves.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VerboseEffectDto {
+    pub effect: String,
+    pub short_effect: String,
+    pub language: NamedApiResource,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AbilityPokemonDto {
+    pub is_hidden: bool,
+    pub pokemon: NamedApiResource,
+}
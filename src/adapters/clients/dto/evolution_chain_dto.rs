@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EvolutionChainResponseDto {
+    pub id: u32,
+    pub chain: ChainLinkDto,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChainLinkDto {
+    pub species: NamedApiResource,
+    pub is_baby: bool,
+    pub evolution_details: Vec<EvolutionDetailDto>,
+    pub evolves_to: Vec<ChainLinkDto>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct EvolutionDetailDto {
+    pub trigger: Option<NamedApiResource>,
+    pub min_level: Option<u8>,
+    pub item: Option<NamedApiResource>,
+    pub held_item: Option<NamedApiResource>,
+    pub known_move: Option<NamedApiResource>,
+    pub known_move_type: Option<NamedApiResource>,
+    pub location: Option<NamedApiResource>,
+    pub min_happiness: Option<u8>,
+    pub min_beauty: Option<u8>,
+    pub min_affection: Option<u8>,
+    pub time_of_day: String,
+    pub gender: Option<u8>,
+    pub needs_overworld_rain: bool,
+    pub party_species: Option<NamedApiResource>,
+    pub party_type: Option<NamedApiResource>,
+    pub relative_physical_stats: Option<i8>,
+    pub trade_species: Option<NamedApiResource>,
+    pub turn_upside_down: bool,
+}
@@ -1,2 +1,10 @@
+pub mod ability_dto;
+pub mod evolution_chain_dto;
+pub mod move_dto;
+pub mod pokedex_dto;
+pub mod pokemon_details_dto;
 pub mod pokemon_species_dto;
+pub mod resource_list_dto;
+pub mod species_group_dto;
 pub mod translations;
+pub mod type_dto;
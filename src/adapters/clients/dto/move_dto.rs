@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::{
+    ability_dto::VerboseEffectDto, pokemon_species_dto::NamedApiResource,
+};
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MoveResponseDto {
+    pub name: String,
+    pub power: Option<u16>,
+    pub accuracy: Option<u8>,
+    pub pp: Option<u8>,
+    pub priority: i8,
+    pub effect_chance: Option<u8>,
+    #[serde(rename = "type")]
+    pub move_type: NamedApiResource,
+    pub damage_class: NamedApiResource,
+    pub effect_entries: Vec<VerboseEffectDto>,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokedexResponseDto {
+    #[allow(dead_code)]
+    pub name: String,
+    pub pokemon_entries: Vec<PokedexEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokedexEntry {
+    pub entry_number: i32,
+    pub pokemon_species: NamedApiResource,
+}
+
+impl PokedexResponseDto {
+    /// Name of the species listed under `number`.
+    pub fn get_species(&self, number: u32) -> Option<&str> {
+        self.pokemon_entries
+            .iter()
+            .find(|entry| u32::try_from(entry.entry_number) == Ok(number))
+            .map(|entry| entry.pokemon_species.name.as_str())
+    }
+}
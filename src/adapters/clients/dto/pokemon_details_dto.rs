@@ -0,0 +1,129 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonDetailsResponseDto {
+    #[allow(dead_code)]
+    pub id: i32,
+    pub name: String,
+    pub species: NamedApiResource,
+    pub height: u32,
+    pub weight: u32,
+    pub types: Vec<PokemonTypeSlot>,
+    pub stats: Vec<PokemonStat>,
+    pub abilities: Vec<PokemonAbility>,
+    #[serde(default)]
+    pub moves: Vec<PokemonMove>,
+    pub sprites: PokemonSprites,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonTypeSlot {
+    pub slot: u8,
+    #[serde(rename = "type")]
+    pub pokemon_type: NamedApiResource,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonStat {
+    pub base_stat: u16,
+    #[allow(dead_code)]
+    pub effort: u8,
+    pub stat: NamedApiResource,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonAbility {
+    pub ability: NamedApiResource,
+    pub is_hidden: bool,
+    pub slot: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonMove {
+    #[serde(rename = "move")]
+    pub pokemon_move: NamedApiResource,
+    pub version_group_details: Vec<PokemonMoveVersion>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonMoveVersion {
+    pub level_learned_at: u8,
+    pub move_learn_method: NamedApiResource,
+    pub version_group: NamedApiResource,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PokemonSprites {
+    pub front_default: Option<String>,
+    pub front_shiny: Option<String>,
+}
+
+impl PokemonDetailsResponseDto {
+    /// Type names ordered by slot (primary type first).
+    pub fn get_types(&self) -> Vec<String> {
+        let mut slots = self.types.clone();
+        slots.sort_by_key(|slot| slot.slot);
+        slots
+            .into_iter()
+            .map(|slot| slot.pokemon_type.name)
+            .collect()
+    }
+
+    pub fn get_base_stat(&self, name: &str) -> u16 {
+        self.stats
+            .iter()
+            .find(|stat| stat.stat.name == name)
+            .map(|stat| stat.base_stat)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(name: &str) -> NamedApiResource {
+        NamedApiResource {
+            name: name.to_string(),
+            url: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_get_types_orders_by_slot() {
+        let dto = PokemonDetailsResponseDto {
+            id: 1,
+            name: "bulbasaur".to_string(),
+            species: resource("bulbasaur"),
+            height: 7,
+            weight: 69,
+            types: vec![
+                PokemonTypeSlot {
+                    slot: 2,
+                    pokemon_type: resource("poison"),
+                },
+                PokemonTypeSlot {
+                    slot: 1,
+                    pokemon_type: resource("grass"),
+                },
+            ],
+            stats: vec![PokemonStat {
+                base_stat: 45,
+                effort: 0,
+                stat: resource("hp"),
+            }],
+            abilities: vec![],
+            moves: vec![],
+            sprites: PokemonSprites {
+                front_default: None,
+                front_shiny: None,
+            },
+        };
+
+        assert_eq!(dto.get_types(), vec!["grass", "poison"]);
+        assert_eq!(dto.get_base_stat("hp"), 45);
+        assert_eq!(dto.get_base_stat("speed"), 0);
+    }
+}
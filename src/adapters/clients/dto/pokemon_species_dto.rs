@@ -2,21 +2,17 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct PokemonSpeciesResponseDto {
-    #[allow(dead_code)]
     pub id: i32,
     pub name: String,
     #[allow(dead_code)]
     pub order: i32,
     #[allow(dead_code)]
     pub gender_rate: i32,
-    #[allow(dead_code)]
     pub capture_rate: i32,
     #[allow(dead_code)]
     pub base_happiness: i32,
-    #[allow(dead_code)]
     pub is_baby: bool,
     pub is_legendary: bool,
-    #[allow(dead_code)]
     pub is_mythical: bool,
     #[allow(dead_code)]
     pub hatch_counter: i32,
@@ -24,60 +20,58 @@ pub struct PokemonSpeciesResponseDto {
     pub has_gender_differences: bool,
     #[allow(dead_code)]
     pub forms_switchable: bool,
-    #[allow(dead_code)]
     pub growth_rate: NamedApiResource,
-    #[allow(dead_code)]
     pub pokedex_numbers: Vec<PokemonDexEntry>,
-    #[allow(dead_code)]
     pub egg_groups: Vec<NamedApiResource>,
-    #[allow(dead_code)]
     pub color: NamedApiResource,
-    #[allow(dead_code)]
     pub shape: NamedApiResource,
-    #[allow(dead_code)]
     pub evolves_from_species: Option<NamedApiResource>,
-    #[allow(dead_code)]
     pub evolution_chain: ApiResource,
     pub habitat: Option<NamedApiResource>,
-    #[allow(dead_code)]
     pub generation: NamedApiResource,
     #[allow(dead_code)]
     pub names: Vec<Name>,
     pub flavor_text_entries: Vec<FlavorText>,
     #[allow(dead_code)]
     pub form_descriptions: Vec<Description>,
-    #[allow(dead_code)]
     pub genera: Vec<Genus>,
-    #[allow(dead_code)]
     pub varieties: Vec<PokemonVariety>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct NamedApiResource {
     pub name: String,
-    #[allow(dead_code)]
     pub url: String,
 }
 
+impl NamedApiResource {
+    /// The id at the end of `url`, e.g. `25` for `.../pokemon-species/25/`.
+    pub fn id(&self) -> Option<u32> {
+        id_from_url(&self.url)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ApiResource {
-    #[allow(dead_code)]
     pub url: String,
 }
 
+fn id_from_url(url: &str) -> Option<u32> {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .and_then(|segment| segment.parse().ok())
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct PokemonDexEntry {
-    #[allow(dead_code)]
     pub entry_number: i32,
-    #[allow(dead_code)]
     pub pokedex: NamedApiResource,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Name {
-    #[allow(dead_code)]
     pub name: String,
-    #[allow(dead_code)]
     pub language: NamedApiResource,
 }
 
@@ -85,12 +79,25 @@ pub struct Name {
 pub struct FlavorText {
     #[serde(rename = "flavor_text")]
     pub text: String,
-    #[allow(dead_code)]
     pub language: NamedApiResource,
-    #[allow(dead_code)]
     pub version: NamedApiResource,
 }
 
+impl FlavorText {
+    /// The text with the line and page breaks PokéAPI keeps from the games
+    /// replaced by spaces.
+    pub fn clean_text(&self) -> String {
+        self.text
+            .replace("\\n", " ")
+            .replace("\n", " ")
+            .replace("\u{0c}", " ")
+            .replace("\\f", " ")
+            .replace("\r", " ")
+            .trim()
+            .to_string()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Description {
     #[allow(dead_code)]
@@ -101,17 +108,13 @@ pub struct Description {
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Genus {
-    #[allow(dead_code)]
     pub genus: String,
-    #[allow(dead_code)]
     pub language: NamedApiResource,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct PokemonVariety {
-    #[allow(dead_code)]
     pub is_default: bool,
-    #[allow(dead_code)]
     pub pokemon: NamedApiResource,
 }
 
@@ -120,17 +123,20 @@ impl PokemonSpeciesResponseDto {
         self.flavor_text_entries
             .iter()
             .find(|entry| entry.language.name == "en")
-            .map(|entry| {
-                entry
-                    .text
-                    .replace("\\n", " ")
-                    .replace("\n", " ")
-                    .replace("\u{0c}", " ")
-                    .replace("\\f", " ")
-                    .replace("\r", " ")
-                    .trim()
-                    .to_string()
-            })
+            .map(FlavorText::clean_text)
+    }
+
+    pub fn get_english_genus(&self) -> Option<String> {
+        self.genera
+            .iter()
+            .find(|entry| entry.language.name == "en")
+            .map(|entry| entry.genus.clone())
+    }
+
+    /// Id of the species' evolution chain, taken from the last segment of its
+    /// resource URL (`.../evolution-chain/67/`).
+    pub fn get_evolution_chain_id(&self) -> Option<u32> {
+        id_from_url(&self.evolution_chain.url)
     }
 
     pub fn get_habitat(&self) -> Option<String> {
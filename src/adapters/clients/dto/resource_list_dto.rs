@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+/// A page of one of PokéAPI's list endpoints, e.g. `/pokemon-species/`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct NamedApiResourceListDto {
+    #[allow(dead_code)]
+    pub count: u32,
+    pub results: Vec<NamedApiResource>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+/// A generation, habitat or color together with the species it contains.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SpeciesGroupDto {
+    pub name: String,
+    pub pokemon_species: Vec<NamedApiResource>,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use crate::adapters::clients::dto::pokemon_species_dto::NamedApiResource;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TypeResponseDto {
+    pub name: String,
+    pub damage_relations: DamageRelationsDto,
+}
+
+/// Only the attacking side; the defending side is the same chart read the
+/// other way round.
+#[derive(Debug, Deserialize, Clone)]
+pub struct DamageRelationsDto {
+    pub double_damage_to: Vec<NamedApiResource>,
+    pub half_damage_to: Vec<NamedApiResource>,
+    pub no_damage_to: Vec<NamedApiResource>,
+}
@@ -1,31 +1,66 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use crate::{
-    adapters::clients::dto::translations::{TranslationRequest, TranslationResponse},
+    adapters::{
+        clients::{
+            circuit_breaker::CircuitBreaker,
+            dto::translations::{TranslationRequest, TranslationResponse},
+            upstream::{UpstreamHttpClient, parse_retry_after},
+        },
+        metrics::{Metrics, UpstreamOutcome},
+    },
     application::app_error::{AppError, AppResult},
+    domain::value_objects::translator::Translator,
 };
 
-pub struct FunsTranslationsApiClient;
+pub struct FunsTranslationsApiClient {
+    http: Arc<UpstreamHttpClient>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    base_url: String,
+    timeout: Duration,
+    metrics: Option<Arc<Metrics>>,
+}
 
 impl FunsTranslationsApiClient {
-    pub fn new() -> Self {
-        FunsTranslationsApiClient {}
+    pub fn new(
+        http: Arc<UpstreamHttpClient>,
+        circuit_breaker: Arc<CircuitBreaker>,
+        base_url: &str,
+        timeout: Duration,
+    ) -> Self {
+        FunsTranslationsApiClient {
+            http,
+            circuit_breaker,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            timeout,
+            metrics: None,
+        }
     }
 
-    pub async fn shakespeare(&self, text: &str) -> AppResult<String> {
-        let url = "https://api.funtranslations.com/translate/shakespeare.json";
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub async fn translate(&self, translator: Translator, text: &str) -> AppResult<String> {
+        let url = format!("{}/translate/{}.json", self.base_url, translator.as_str());
 
         let request_body = TranslationRequest {
             text: text.to_string(),
         };
 
-        let client = reqwest::Client::new();
-        let response = client
-            .post(url)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::GenericError(format!("Failed to fetch from FunTranslations API: {}", e))
-            })?;
+        let response = self
+            .send_guarded(
+                translator,
+                self.http
+                    .post(&url)
+                    .timeout(self.timeout)
+                    .json(&request_body),
+            )
+            .await?;
 
         match response.status() {
             reqwest::StatusCode::OK => {
@@ -37,11 +72,15 @@ impl FunsTranslationsApiClient {
                         ))
                     })?;
 
-                tracing::debug!("Translated: {}", translation_response.contents.translated);
+                tracing::debug!(
+                    translation_style = %translator,
+                    translated = %translation_response.contents.translated,
+                    "Translated description"
+                );
 
                 Ok(translation_response.contents.translated)
             }
-            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(AppError::GenericError(
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(AppError::RateLimited(
                 "Rate limit exceeded for FunTranslations API".to_string(),
             )),
             reqwest::StatusCode::BAD_REQUEST => Err(AppError::BadRequest(
@@ -54,47 +93,52 @@ impl FunsTranslationsApiClient {
         }
     }
 
-    pub async fn yoda(&self, text: &str) -> AppResult<String> {
-        let url = "https://api.funtranslations.com/translate/yoda.json";
-
-        let request_body = TranslationRequest {
-            text: text.to_string(),
+    /// Sends a request through the circuit breaker, failing fast while it is
+    /// open. Rate limiting and server errors count as failures.
+    async fn send_guarded(
+        &self,
+        translator: Translator,
+        request: reqwest::RequestBuilder,
+    ) -> AppResult<reqwest::Response> {
+        let Some(permit) = self.circuit_breaker.try_acquire() else {
+            return Err(AppError::ServiceUnavailable(
+                "FunTranslations API circuit is open".to_string(),
+            ));
         };
 
-        let client = reqwest::Client::new();
-        let response = client
-            .post(url)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::GenericError(format!("Failed to fetch from FunTranslations API: {}", e))
-            })?;
-
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let translation_response =
-                    response.json::<TranslationResponse>().await.map_err(|e| {
-                        AppError::GenericError(format!(
-                            "Failed to parse FunTranslations response: {}",
-                            e
-                        ))
-                    })?;
-
-                tracing::debug!("Translated: {}", translation_response.contents.translated);
+        let started = Instant::now();
+        let response = self.http.send(request).await;
+        let (outcome, elapsed) = (UpstreamOutcome::of(&response), started.elapsed());
+        tracing::debug!(
+            upstream = "funtranslations",
+            translation_style = %translator,
+            upstream_status = response.as_ref().ok().map(|r| r.status().as_u16()),
+            outcome = outcome.as_str(),
+            elapsed_ms = elapsed.as_millis() as u64,
+            "Upstream request completed"
+        );
+        if let Some(metrics) = &self.metrics {
+            metrics.record_upstream("funtranslations", translator.as_str(), outcome, elapsed);
+        }
 
-                Ok(translation_response.contents.translated)
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                permit.record_failure(None);
+                return Err(AppError::GenericError(format!(
+                    "Failed to fetch from FunTranslations API: {}",
+                    e
+                )));
             }
-            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(AppError::GenericError(
-                "Rate limit exceeded for FunTranslations API".to_string(),
-            )),
-            reqwest::StatusCode::BAD_REQUEST => Err(AppError::BadRequest(
-                "Invalid request to FunTranslations API".to_string(),
-            )),
-            status => Err(AppError::GenericError(format!(
-                "FunTranslations API error: {}",
-                status
-            ))),
+        };
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            permit.record_failure(parse_retry_after(&response));
+        } else {
+            permit.record_success();
         }
+
+        Ok(response)
     }
 }
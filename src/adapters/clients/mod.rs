@@ -1,3 +1,5 @@
+pub mod circuit_breaker;
 pub mod dto;
 pub mod funtranslations;
 pub mod pokeapi;
+pub mod upstream;
@@ -1,36 +1,180 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde::de::DeserializeOwned;
+
 use crate::{
-    adapters::clients::dto::pokemon_species_dto::PokemonSpeciesResponseDto,
+    adapters::{
+        clients::{
+            dto::{
+                ability_dto::AbilityResponseDto, evolution_chain_dto::EvolutionChainResponseDto,
+                move_dto::MoveResponseDto, pokedex_dto::PokedexResponseDto,
+                pokemon_details_dto::PokemonDetailsResponseDto,
+                pokemon_species_dto::PokemonSpeciesResponseDto,
+                resource_list_dto::NamedApiResourceListDto, species_group_dto::SpeciesGroupDto,
+                type_dto::TypeResponseDto,
+            },
+            upstream::UpstreamHttpClient,
+        },
+        metrics::{Metrics, UpstreamOutcome},
+    },
     application::app_error::{AppError, AppResult},
 };
 
-pub struct PokeApiClient;
+/// Comfortably above the number of resources of any kind PokéAPI has.
+const LIST_LIMIT: u32 = 10_000;
+
+pub struct PokeApiClient {
+    http: Arc<UpstreamHttpClient>,
+    base_url: String,
+    timeout: Duration,
+    metrics: Option<Arc<Metrics>>,
+}
 
 impl PokeApiClient {
-    pub fn new() -> Self {
-        PokeApiClient {}
+    pub fn new(http: Arc<UpstreamHttpClient>, base_url: &str, timeout: Duration) -> Self {
+        PokeApiClient {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            timeout,
+            metrics: None,
+        }
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
     }
 
     pub async fn pokemon_species(&self, name: &str) -> AppResult<PokemonSpeciesResponseDto> {
-        let url = format!("https://pokeapi.co/api/v2/pokemon-species/{}/", name);
+        self.get_json(
+            &format!("{}/pokemon-species/{}/", self.base_url, name),
+            || format!("Pokemon species '{}' not found", name),
+        )
+        .await
+    }
 
-        let response = reqwest::get(&url)
-            .await
+    /// Every species, in a single page.
+    pub async fn pokemon_species_list(&self) -> AppResult<NamedApiResourceListDto> {
+        self.resource_list("pokemon-species").await
+    }
+
+    /// Every resource of a kind such as `generation` or `pokemon-color`, in a
+    /// single page.
+    pub async fn resource_list(&self, resource: &str) -> AppResult<NamedApiResourceListDto> {
+        self.get_json(
+            &format!("{}/{}/?limit={}", self.base_url, resource, LIST_LIMIT),
+            || format!("PokeAPI resource '{}' not found", resource),
+        )
+        .await
+    }
+
+    /// A `generation`, `pokemon-habitat` or `pokemon-color` and its species.
+    pub async fn species_group(&self, resource: &str, name: &str) -> AppResult<SpeciesGroupDto> {
+        self.get_json(&format!("{}/{}/{}/", self.base_url, resource, name), || {
+            format!("PokeAPI {} '{}' not found", resource, name)
+        })
+        .await
+    }
+
+    pub async fn pokemon(&self, name: &str) -> AppResult<PokemonDetailsResponseDto> {
+        self.get_json(&format!("{}/pokemon/{}/", self.base_url, name), || {
+            format!("Pokemon '{}' not found", name)
+        })
+        .await
+    }
+
+    pub async fn evolution_chain(&self, id: u32) -> AppResult<EvolutionChainResponseDto> {
+        self.get_json(
+            &format!("{}/evolution-chain/{}/", self.base_url, id),
+            || format!("Evolution chain {} not found", id),
+        )
+        .await
+    }
+
+    pub async fn pokedex(&self, name: &str) -> AppResult<PokedexResponseDto> {
+        self.get_json(&format!("{}/pokedex/{}/", self.base_url, name), || {
+            format!("Pokedex '{}' not found", name)
+        })
+        .await
+    }
+
+    pub async fn pokemon_type(&self, name: &str) -> AppResult<TypeResponseDto> {
+        self.get_json(&format!("{}/type/{}/", self.base_url, name), || {
+            format!("Type '{}' not found", name)
+        })
+        .await
+    }
+
+    pub async fn ability(&self, name: &str) -> AppResult<AbilityResponseDto> {
+        self.get_json(&format!("{}/ability/{}/", self.base_url, name), || {
+            format!("Ability '{}' not found", name)
+        })
+        .await
+    }
+
+    pub async fn pokemon_move(&self, name: &str) -> AppResult<MoveResponseDto> {
+        self.get_json(&format!("{}/move/{}/", self.base_url, name), || {
+            format!("Move '{}' not found", name)
+        })
+        .await
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        not_found_message: impl FnOnce() -> String,
+    ) -> AppResult<T> {
+        let started = Instant::now();
+        let response = self
+            .http
+            .send(self.http.get(url).timeout(self.timeout))
+            .await;
+        let (endpoint, outcome, elapsed) = (
+            self.resource_of(url),
+            UpstreamOutcome::of(&response),
+            started.elapsed(),
+        );
+        tracing::debug!(
+            upstream = "pokeapi",
+            endpoint,
+            upstream_status = response.as_ref().ok().map(|r| r.status().as_u16()),
+            outcome = outcome.as_str(),
+            elapsed_ms = elapsed.as_millis() as u64,
+            "Upstream request completed"
+        );
+        if let Some(metrics) = &self.metrics {
+            metrics.record_upstream("pokeapi", endpoint, outcome, elapsed);
+        }
+        let response = response
             .map_err(|e| AppError::GenericError(format!("Failed to fetch from PokeAPI: {}", e)))?;
 
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(AppError::NotFound(not_found_message()));
+        }
+
         if !response.status().is_success() {
-            return Err(AppError::NotFound(format!(
-                "Pokemon species '{}' not found",
-                name
+            return Err(AppError::GenericError(format!(
+                "PokeAPI error: {}",
+                response.status()
             )));
         }
 
-        let pokemon = response
-            .json::<PokemonSpeciesResponseDto>()
+        response
+            .json::<T>()
             .await
-            .map_err(|e| {
-                AppError::GenericError(format!("Failed to parse PokeAPI response: {}", e))
-            })?;
+            .map_err(|e| AppError::GenericError(format!("Failed to parse PokeAPI response: {}", e)))
+    }
 
-        Ok(pokemon)
+    /// The resource kind a URL points at, e.g. `pokemon-species`.
+    fn resource_of<'a>(&self, url: &'a str) -> &'a str {
+        url.strip_prefix(&self.base_url)
+            .unwrap_or(url)
+            .trim_start_matches('/')
+            .split(['/', '?'])
+            .next()
+            .unwrap_or_default()
     }
 }
@@ -0,0 +1,214 @@
+use std::time::{Duration, SystemTime};
+
+use reqwest::{RequestBuilder, Response, StatusCode, header::RETRY_AFTER};
+
+use crate::application::request_id::RequestId;
+
+/// Header carrying the request id, both from clients and to upstreams.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+/// Pooled HTTP client shared by every upstream API client.
+///
+/// Retries connection errors, timeouts and 5xx responses with bounded
+/// exponential backoff. A `Retry-After` header takes precedence over the
+/// computed backoff; if it asks for longer than `max_delay` the response is
+/// handed back to the caller instead of waiting. Timeouts and error responses
+/// are only retried for idempotent requests, so a POST never spends an
+/// upstream's quota twice; refused connections, where nothing was sent, are
+/// retried for any method. Every request carries the id of the request being
+/// served, if any.
+pub struct UpstreamHttpClient {
+    client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl UpstreamHttpClient {
+    pub fn new(
+        connect_timeout: Duration,
+        read_timeout: Duration,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(connect_timeout)
+            .read_timeout(read_timeout)
+            .build()
+            .expect("Failed to build upstream HTTP client");
+
+        Self {
+            client,
+            retry_policy,
+        }
+    }
+
+    pub fn get(&self, url: &str) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let request = match RequestId::current() {
+            Some(id) => request.header(REQUEST_ID_HEADER, id.as_str()),
+            None => request,
+        };
+        let idempotent = is_idempotent(&request);
+        let max_retries = self.retry_policy.max_retries;
+        let mut attempt = 0;
+
+        loop {
+            let current = request
+                .try_clone()
+                .expect("Upstream requests must have a cloneable body");
+
+            let delay = match current.send().await {
+                Ok(response) if attempt < max_retries && idempotent => {
+                    match self.retry_delay(&response, attempt) {
+                        Some(delay) => {
+                            tracing::warn!(
+                                url = %response.url(),
+                                upstream_status = response.status().as_u16(),
+                                retry_in_ms = delay.as_millis() as u64,
+                                attempt = attempt + 1,
+                                max_retries,
+                                "Upstream request failed, retrying"
+                            );
+                            delay
+                        }
+                        None => return Ok(response),
+                    }
+                }
+                Err(e)
+                    if attempt < max_retries
+                        && (e.is_connect() || (idempotent && e.is_timeout())) =>
+                {
+                    let delay = self.retry_policy.backoff(attempt);
+                    tracing::warn!(
+                        url = e.url().map(|url| url.as_str()),
+                        error = %e,
+                        retry_in_ms = delay.as_millis() as u64,
+                        attempt = attempt + 1,
+                        max_retries,
+                        "Upstream request failed, retrying"
+                    );
+                    delay
+                }
+                result => return result,
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn retry_delay(&self, response: &Response, attempt: u32) -> Option<Duration> {
+        let retry_after = parse_retry_after(response);
+        let retryable = response.status().is_server_error()
+            || (response.status() == StatusCode::TOO_MANY_REQUESTS && retry_after.is_some());
+
+        if !retryable {
+            return None;
+        }
+
+        match retry_after {
+            Some(delay) if delay > self.retry_policy.max_delay => None,
+            Some(delay) => Some(delay),
+            None => Some(self.retry_policy.backoff(attempt)),
+        }
+    }
+}
+
+fn is_idempotent(request: &RequestBuilder) -> bool {
+    request
+        .try_clone()
+        .and_then(|request| request.build().ok())
+        .is_some_and(|request| request.method().is_idempotent())
+}
+
+pub fn parse_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after_value(value)
+}
+
+fn parse_retry_after_value(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()
+        .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_exponentially_and_is_bounded() {
+        let policy = policy();
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_only_idempotent_requests_are_retried() {
+        let client = reqwest::Client::new();
+
+        assert!(is_idempotent(&client.get("http://localhost/pokemon/")));
+        assert!(!is_idempotent(
+            &client.post("http://localhost/translate/yoda")
+        ));
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        assert_eq!(
+            parse_retry_after_value(" 120 "),
+            Some(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date_in_the_past() {
+        assert_eq!(
+            parse_retry_after_value("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_rejects_garbage() {
+        assert_eq!(parse_retry_after_value("soon"), None);
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{application::use_cases::pokemon_use_cases::PokemonUseCases, infra::config::AppConfig};
+use crate::{
+    adapters::{clients::circuit_breaker::CircuitBreaker, metrics::Metrics},
+    application::use_cases::{
+        ability_use_cases::AbilityUseCases, evolution_use_cases::EvolutionUseCases,
+        move_use_cases::MoveUseCases, pokemon_use_cases::PokemonUseCases,
+        type_use_cases::TypeUseCases,
+    },
+    infra::config::AppConfig,
+};
 use axum::extract::FromRef;
 use std::sync::Arc;
 
@@ -6,6 +14,12 @@ use std::sync::Arc;
 pub struct AppState {
     pub config: AppConfig,
     pub pokemon_use_cases: Arc<PokemonUseCases>,
+    pub evolution_use_cases: Arc<EvolutionUseCases>,
+    pub type_use_cases: Arc<TypeUseCases>,
+    pub ability_use_cases: Arc<AbilityUseCases>,
+    pub move_use_cases: Arc<MoveUseCases>,
+    pub fun_translations_circuit: Arc<CircuitBreaker>,
+    pub metrics: Arc<Metrics>,
 }
 
 impl FromRef<AppState> for Arc<PokemonUseCases> {
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::{
+    entities::ability_details::AbilityDetails,
+    value_objects::{effect_text::EffectText, language::LanguagePreference},
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EffectQuery {
+    /// PokéAPI language name (`fr`, `ja-Hrkt`, ...) of the effect text. Takes
+    /// precedence over `Accept-Language`.
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AbilityResponseDto {
+    name: String,
+    /// Omitted when PokéAPI has no effect text for the ability.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    effect: Option<EffectDto>,
+    pokemon: Vec<AbilityHolderDto>,
+}
+
+/// Effect text in the best available language, falling back to English.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectDto {
+    language: String,
+    effect: String,
+    short_effect: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AbilityHolderDto {
+    name: String,
+    is_hidden: bool,
+}
+
+impl AbilityResponseDto {
+    pub fn new(ability: &AbilityDetails, language: &LanguagePreference) -> Self {
+        Self {
+            name: ability.name().to_string(),
+            effect: ability.effect(language).map(EffectDto::from),
+            pokemon: ability
+                .pokemon()
+                .iter()
+                .map(|holder| AbilityHolderDto {
+                    name: holder.name.clone(),
+                    is_hidden: holder.is_hidden,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&EffectText> for EffectDto {
+    fn from(effect: &EffectText) -> Self {
+        Self {
+            language: effect.language.clone(),
+            effect: effect.effect.clone(),
+            short_effect: effect.short_effect.clone(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::adapters::http::dto::pokemon_dto::PokemonResponseDto;
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::entities::pokemon::Pokemon;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRequestDto {
+    /// Names or national dex numbers, e.g. a team of six.
+    pub names: Vec<String>,
+    /// Translate descriptions as `/pokemon/translated/{name}` does.
+    #[serde(default)]
+    pub translated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponseDto {
+    /// One entry per requested name, in request order.
+    results: Vec<BatchItemDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemDto {
+    name: String,
+    /// The status `/pokemon/{name}` would have answered with.
+    status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pokemon: Option<PokemonResponseDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<BatchErrorDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchErrorDto {
+    code: String,
+    message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
+}
+
+impl BatchResponseDto {
+    pub fn new(names: Vec<String>, results: Vec<AppResult<Pokemon>>) -> Self {
+        Self {
+            results: names
+                .into_iter()
+                .zip(results)
+                .map(|(name, result)| BatchItemDto::new(name, result))
+                .collect(),
+        }
+    }
+}
+
+impl BatchItemDto {
+    fn new(name: String, result: AppResult<Pokemon>) -> Self {
+        match result {
+            Ok(pokemon) => Self {
+                name,
+                status: 200,
+                pokemon: Some(PokemonResponseDto::from(&pokemon)),
+                error: None,
+            },
+            Err(e) => Self {
+                name,
+                status: e.status_code().as_u16(),
+                pokemon: None,
+                error: Some(BatchErrorDto {
+                    code: e.error_code().to_string(),
+                    message: e.to_string(),
+                    suggestions: match e {
+                        AppError::NotFoundWithSuggestions { suggestions, .. } => suggestions,
+                        _ => Vec::new(),
+                    },
+                }),
+            },
+        }
+    }
+}
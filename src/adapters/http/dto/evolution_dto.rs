@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::evolution_chain::{EvolutionChain, EvolutionStage},
+    value_objects::evolution_condition::EvolutionCondition,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionChainResponseDto {
+    id: u32,
+    chain: EvolutionStageDto,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionStageDto {
+    species: String,
+    is_baby: bool,
+    /// Ways of evolving into this stage; empty for the first stage.
+    conditions: Vec<EvolutionConditionDto>,
+    #[schema(no_recursion)]
+    evolves_to: Vec<EvolutionStageDto>,
+}
+
+/// Requirements that are not set are omitted.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionConditionDto {
+    /// `level-up`, `trade`, `use-item`, `shed`, ...
+    trigger: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_level: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    item: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    held_item: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    known_move: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    known_move_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_happiness: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_beauty: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_affection: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    time_of_day: Option<String>,
+    /// PokéAPI gender id: 1 female, 2 male.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gender: Option<u8>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    needs_overworld_rain: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    party_species: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    party_type: Option<String>,
+    /// 1: Attack > Defense, 0: equal, -1: Attack < Defense.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    relative_physical_stats: Option<i8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    trade_species: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    turn_upside_down: bool,
+}
+
+impl From<&EvolutionChain> for EvolutionChainResponseDto {
+    fn from(chain: &EvolutionChain) -> Self {
+        Self {
+            id: chain.id(),
+            chain: EvolutionStageDto::from(chain.root()),
+        }
+    }
+}
+
+impl From<&EvolutionStage> for EvolutionStageDto {
+    fn from(stage: &EvolutionStage) -> Self {
+        Self {
+            species: stage.species().to_string(),
+            is_baby: stage.is_baby(),
+            conditions: stage
+                .conditions()
+                .iter()
+                .map(EvolutionConditionDto::from)
+                .collect(),
+            evolves_to: stage
+                .evolves_to()
+                .iter()
+                .map(EvolutionStageDto::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&EvolutionCondition> for EvolutionConditionDto {
+    fn from(condition: &EvolutionCondition) -> Self {
+        let condition = condition.clone();
+
+        Self {
+            trigger: condition.trigger.as_str().to_string(),
+            min_level: condition.min_level,
+            item: condition.item,
+            held_item: condition.held_item,
+            known_move: condition.known_move,
+            known_move_type: condition.known_move_type,
+            location: condition.location,
+            min_happiness: condition.min_happiness,
+            min_beauty: condition.min_beauty,
+            min_affection: condition.min_affection,
+            time_of_day: condition.time_of_day,
+            gender: condition.gender,
+            needs_overworld_rain: condition.needs_overworld_rain,
+            party_species: condition.party_species,
+            party_type: condition.party_type,
+            relative_physical_stats: condition.relative_physical_stats,
+            trade_species: condition.trade_species,
+            turn_upside_down: condition.turn_upside_down,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponseDto {
+    status: &'static str,
+    circuit_breakers: CircuitBreakersDto,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakersDto {
+    fun_translations: &'static str,
+}
+
+impl HealthResponseDto {
+    pub fn new(fun_translations: &'static str) -> Self {
+        Self {
+            status: "ok",
+            circuit_breakers: CircuitBreakersDto { fun_translations },
+        }
+    }
+}
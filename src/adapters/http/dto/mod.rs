@@ -1 +1,9 @@
+pub mod ability_dto;
+pub mod batch_dto;
+pub mod evolution_dto;
+pub mod health_dto;
+pub mod move_dto;
 pub mod pokemon_dto;
+pub mod pokemon_list_dto;
+pub mod search_dto;
+pub mod type_dto;
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::adapters::http::dto::ability_dto::EffectDto;
+use crate::domain::{
+    entities::{move_details::MoveDetails, pokemon::Pokemon},
+    value_objects::{
+        language::LanguagePreference,
+        learned_move::{LearnedMove, LearnsetFilter},
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveResponseDto {
+    name: String,
+    #[serde(rename = "type")]
+    move_type: String,
+    /// `physical`, `special` or `status`.
+    damage_class: String,
+    /// Unset for moves without a fixed power, e.g. status moves.
+    power: Option<u16>,
+    /// Unset for moves that never miss.
+    accuracy: Option<u8>,
+    pp: Option<u8>,
+    priority: i8,
+    /// Percent chance of the secondary effect.
+    effect_chance: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    effect: Option<EffectDto>,
+}
+
+impl MoveResponseDto {
+    pub fn new(pokemon_move: &MoveDetails, language: &LanguagePreference) -> Self {
+        let stats = pokemon_move.stats();
+
+        Self {
+            name: pokemon_move.name().to_string(),
+            move_type: pokemon_move.move_type().to_string(),
+            damage_class: pokemon_move.damage_class().to_string(),
+            power: stats.power,
+            accuracy: stats.accuracy,
+            pp: stats.pp,
+            priority: stats.priority,
+            effect_chance: stats.effect_chance,
+            effect: pokemon_move.effect(language).as_ref().map(EffectDto::from),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LearnsetQuery {
+    /// Game version group, e.g. `red-blue` or `scarlet-violet`.
+    pub version_group: Option<String>,
+    /// Learn method: `level-up`, `machine`, `egg`, `tutor`, ...
+    pub method: Option<String>,
+}
+
+impl LearnsetQuery {
+    pub fn filter(&self) -> LearnsetFilter {
+        let value = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        LearnsetFilter {
+            version_group: value(&self.version_group),
+            method: value(&self.method),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PokemonMovesResponseDto {
+    name: String,
+    /// One entry per move, learn method and version group, grouped by
+    /// method and in level order.
+    moves: Vec<LearnedMoveDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LearnedMoveDto {
+    name: String,
+    method: String,
+    /// Only set for `level-up` moves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    level: Option<u8>,
+    version_group: String,
+}
+
+impl PokemonMovesResponseDto {
+    pub fn new(pokemon: &Pokemon, learnset: &[LearnedMove]) -> Self {
+        Self {
+            name: pokemon.name().to_string(),
+            moves: learnset
+                .iter()
+                .map(|learned| LearnedMoveDto {
+                    name: learned.name.clone(),
+                    method: learned.method.clone(),
+                    level: learned.level,
+                    version_group: learned.version_group.clone(),
+                })
+                .collect(),
+        }
+    }
+}
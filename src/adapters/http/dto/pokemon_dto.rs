@@ -1,22 +1,330 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::{entities::pokemon::Pokemon, value_objects::translation::TranslationDetails};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PokemonResponseDto {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
     name: String,
+    /// Species name in the response language, e.g. `Pikachu`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
     description: String,
     habitat: String,
     is_legendary: bool,
+    #[serde(default)]
+    is_mythical: bool,
+    #[serde(default)]
+    is_baby: bool,
+    /// PokéAPI generation name, e.g. `generation-i`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    generation: Option<String>,
+    /// Genus in the language from `lang` or `Accept-Language`, falling back to
+    /// English, e.g. `Mouse Pokémon`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    genus: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shape: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    growth_rate: Option<String>,
+    #[serde(default)]
+    egg_groups: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    capture_rate: Option<u8>,
+    #[serde(default)]
+    varieties: Vec<VarietyDto>,
+    /// The variety that was looked up, or the species' default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variety: Option<VarietyDto>,
+    /// Entry numbers in the national and regional Pokedexes.
+    #[serde(default)]
+    pokedex_numbers: Vec<PokedexNumberDto>,
+    /// Species this one evolves from, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    evolves_from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    types: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stats: Option<BaseStatsDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    abilities: Option<Vec<AbilityDto>>,
+    /// Height in decimetres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    height: Option<u32>,
+    /// Weight in hectograms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weight: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sprites: Option<SpritesDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    translation: Option<TranslationMetadataDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VarietyDto {
+    name: String,
+    is_default: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PokedexNumberDto {
+    pokedex: String,
+    entry_number: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseStatsDto {
+    hp: u16,
+    attack: u16,
+    defense: u16,
+    special_attack: u16,
+    special_defense: u16,
+    speed: u16,
+    total: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AbilityDto {
+    name: String,
+    is_hidden: bool,
+    slot: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpritesDto {
+    front_default: Option<String>,
+    front_shiny: Option<String>,
+}
+
+/// How the description of a translated Pokemon was produced.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationMetadataDto {
+    original_description: String,
+    style: String,
+    from_cache: bool,
+    /// `rate_limited`, `service_unavailable` or `translation_failed` when the
+    /// original description was returned untranslated.
+    fallback_reason: Option<String>,
+}
+
+impl From<&Pokemon> for PokemonResponseDto {
+    fn from(pokemon: &Pokemon) -> Self {
+        let response = Self {
+            id: pokemon.id(),
+            name: pokemon.name().to_string(),
+            display_name: pokemon.display_name().map(str::to_string),
+            description: pokemon.description().to_string(),
+            habitat: pokemon.habitat().to_string(),
+            is_legendary: pokemon.is_legendary(),
+            is_mythical: pokemon.is_mythical(),
+            is_baby: pokemon.is_baby(),
+            generation: pokemon.generation().map(|g| g.to_string()),
+            genus: pokemon.genus().map(str::to_string),
+            color: pokemon.color().map(str::to_string),
+            shape: pokemon.shape().map(str::to_string),
+            growth_rate: pokemon.growth_rate().map(|g| g.to_string()),
+            egg_groups: pokemon.egg_groups().iter().map(|g| g.to_string()).collect(),
+            capture_rate: pokemon.capture_rate(),
+            varieties: pokemon
+                .varieties()
+                .iter()
+                .map(|variety| VarietyDto {
+                    name: variety.name.clone(),
+                    is_default: variety.is_default,
+                })
+                .collect(),
+            variety: pokemon.variety().map(|variety| VarietyDto {
+                name: variety.name.clone(),
+                is_default: variety.is_default,
+            }),
+            pokedex_numbers: pokemon
+                .pokedex_numbers()
+                .iter()
+                .map(|number| PokedexNumberDto {
+                    pokedex: number.pokedex.clone(),
+                    entry_number: number.entry_number,
+                })
+                .collect(),
+            evolves_from: pokemon.evolves_from().map(str::to_string),
+            types: None,
+            stats: None,
+            abilities: None,
+            height: None,
+            weight: None,
+            sprites: None,
+            translation: None,
+        };
+
+        match pokemon.translation() {
+            Some(translation) => response.with_translation(translation),
+            None => response,
+        }
+    }
 }
 
 impl PokemonResponseDto {
-    pub fn new(name: String, description: String, habitat: String, is_legendary: bool) -> Self {
+    /// Adds the requested detail sections. `pokemon` must come from a detailed
+    /// lookup for them to be populated.
+    pub fn with_includes(mut self, pokemon: &Pokemon, includes: &[PokemonInclude]) -> Self {
+        for include in includes {
+            match include {
+                PokemonInclude::Types => self.types = Some(pokemon.types().to_vec()),
+                PokemonInclude::Stats => {
+                    self.stats = pokemon.stats().map(|stats| BaseStatsDto {
+                        hp: stats.hp,
+                        attack: stats.attack,
+                        defense: stats.defense,
+                        special_attack: stats.special_attack,
+                        special_defense: stats.special_defense,
+                        speed: stats.speed,
+                        total: stats.total(),
+                    })
+                }
+                PokemonInclude::Abilities => {
+                    self.abilities = Some(
+                        pokemon
+                            .abilities()
+                            .iter()
+                            .map(|ability| AbilityDto {
+                                name: ability.name.clone(),
+                                is_hidden: ability.is_hidden,
+                                slot: ability.slot,
+                            })
+                            .collect(),
+                    )
+                }
+                PokemonInclude::Size => {
+                    self.height = pokemon.height();
+                    self.weight = pokemon.weight();
+                }
+                PokemonInclude::Sprites => {
+                    self.sprites = pokemon.sprites().map(|sprites| SpritesDto {
+                        front_default: sprites.front_default.clone(),
+                        front_shiny: sprites.front_shiny.clone(),
+                    })
+                }
+            }
+        }
+        self
+    }
+
+    fn with_translation(mut self, translation: &TranslationDetails) -> Self {
+        self.translation = Some(TranslationMetadataDto {
+            original_description: translation.original_description.clone(),
+            style: translation.translator.to_string(),
+            from_cache: translation.from_cache,
+            fallback_reason: translation
+                .fallback_reason
+                .map(|reason| reason.as_str().to_string()),
+        });
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TranslatedPokemonQuery {
+    /// FunTranslations dialect to use, e.g. `pirate`. Defaults to the
+    /// translation policy's choice.
+    pub style: Option<String>,
+}
+
+/// Optional sections of `GET /pokemon/{name}` that need PokéAPI's `/pokemon`
+/// endpoint on top of the species lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PokemonInclude {
+    Types,
+    Stats,
+    Abilities,
+    Size,
+    Sprites,
+}
+
+impl FromStr for PokemonInclude {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "types" => Ok(PokemonInclude::Types),
+            "stats" => Ok(PokemonInclude::Stats),
+            "abilities" => Ok(PokemonInclude::Abilities),
+            "size" => Ok(PokemonInclude::Size),
+            "sprites" => Ok(PokemonInclude::Sprites),
+            other => Err(format!("unknown include: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PokemonQuery {
+    /// Comma-separated extra sections: `types`, `stats`, `abilities`, `size`,
+    /// `sprites`.
+    pub include: Option<String>,
+    /// PokéAPI language name (`fr`, `ja-Hrkt`, ...). Takes precedence over
+    /// `Accept-Language`.
+    pub lang: Option<String>,
+    /// Game version to take the description from, e.g. `red`.
+    pub version: Option<String>,
+    /// Regional Pokedex, e.g. `kanto`, in which a numeric `pokemon_name` is
+    /// looked up instead of the national dex.
+    pub pokedex: Option<String>,
+}
+
+impl PokemonQuery {
+    pub fn includes(&self) -> Result<Vec<PokemonInclude>, String> {
+        self.include
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter(|value| !value.trim().is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlavorTextsResponseDto {
+    name: String,
+    flavor_texts: Vec<FlavorTextGroupDto>,
+}
+
+/// A text shared word for word by `versions`.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlavorTextGroupDto {
+    language: String,
+    text: String,
+    versions: Vec<String>,
+}
+
+impl From<&Pokemon> for FlavorTextsResponseDto {
+    fn from(pokemon: &Pokemon) -> Self {
         Self {
-            name,
-            description,
-            habitat,
-            is_legendary,
+            name: pokemon.name().to_string(),
+            flavor_texts: pokemon
+                .flavor_text_groups()
+                .into_iter()
+                .map(|group| FlavorTextGroupDto {
+                    language: group.language,
+                    text: group.text,
+                    versions: group.versions,
+                })
+                .collect(),
         }
     }
 }
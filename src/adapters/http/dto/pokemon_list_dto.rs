@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::value_objects::{
+    generation::Generation, page::Page, species_filter::SpeciesFilter,
+    species_summary::SpeciesSummary,
+};
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PokemonListQuery {
+    /// Page size, 20 by default and at most 100.
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    /// PokéAPI generation name, e.g. `generation-i`.
+    pub generation: Option<String>,
+    /// PokéAPI habitat name, e.g. `waters-edge`.
+    pub habitat: Option<String>,
+    pub legendary: Option<bool>,
+    pub mythical: Option<bool>,
+    /// PokéAPI color name, e.g. `yellow`.
+    pub color: Option<String>,
+}
+
+impl PokemonListQuery {
+    pub fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset.unwrap_or_default()
+    }
+
+    pub fn filter(&self) -> Result<SpeciesFilter, String> {
+        Ok(SpeciesFilter {
+            generation: self
+                .generation
+                .as_deref()
+                .map(str::parse::<Generation>)
+                .transpose()?,
+            habitat: self.habitat.as_deref().map(slug),
+            color: self.color.as_deref().map(slug),
+            is_legendary: self.legendary,
+            is_mythical: self.mythical,
+        })
+    }
+
+    /// Link to the page at `offset`, keeping the other parameters.
+    fn page_link(&self, offset: usize) -> String {
+        let mut params = vec![
+            ("limit", self.limit().to_string()),
+            ("offset", offset.to_string()),
+        ];
+        let filters = [
+            ("generation", self.generation.clone()),
+            ("habitat", self.habitat.clone()),
+            ("legendary", self.legendary.map(|v| v.to_string())),
+            ("mythical", self.mythical.map(|v| v.to_string())),
+            ("color", self.color.clone()),
+        ];
+        params.extend(
+            filters
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value?))),
+        );
+
+        let query = serde_urlencoded::to_string(&params).expect("string pairs always encode");
+        format!("/pokemon?{query}")
+    }
+}
+
+/// Lowercases PokéAPI names typed with spaces, e.g. `Waters Edge`.
+fn slug(value: &str) -> String {
+    value.trim().to_lowercase().replace(' ', "-")
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PokemonListResponseDto {
+    /// Number of species matching the filters, across all pages.
+    count: usize,
+    next: Option<String>,
+    prev: Option<String>,
+    results: Vec<PokemonSummaryDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PokemonSummaryDto {
+    /// National dex number.
+    id: u32,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    generation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    habitat: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    url: String,
+}
+
+impl PokemonListResponseDto {
+    pub fn new(page: Page<SpeciesSummary>, query: &PokemonListQuery) -> Self {
+        let (offset, limit) = (query.offset(), query.limit());
+        let next_offset = offset.saturating_add(limit);
+        // Past the end, step back to the last page that has results.
+        let prev_offset = if offset < page.total {
+            offset.saturating_sub(limit)
+        } else {
+            page.total.saturating_sub(1) / limit * limit
+        };
+
+        Self {
+            count: page.total,
+            next: (next_offset < page.total).then(|| query.page_link(next_offset)),
+            prev: (offset > 0).then(|| query.page_link(prev_offset)),
+            results: page
+                .items
+                .into_iter()
+                .map(|species| PokemonSummaryDto {
+                    id: species.id,
+                    url: format!("/pokemon/{}", species.name),
+                    name: species.name,
+                    generation: species.generation.map(|g| g.to_string()),
+                    habitat: species.habitat,
+                    color: species.color,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_huge_offset_has_no_next_page() {
+        let query = PokemonListQuery {
+            limit: Some(20),
+            offset: Some(usize::MAX),
+            generation: None,
+            habitat: None,
+            legendary: None,
+            mythical: None,
+            color: None,
+        };
+        let page = Page {
+            items: Vec::new(),
+            total: 5,
+        };
+
+        let response = PokemonListResponseDto::new(page, &query);
+
+        assert_eq!(response.next, None);
+        assert_eq!(
+            response.prev,
+            Some("/pokemon?limit=20&offset=0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_offset_past_the_end_links_back_to_the_last_page() {
+        let query = PokemonListQuery {
+            limit: Some(20),
+            offset: Some(100),
+            generation: Some("generation-i".to_string()),
+            habitat: Some("waters edge".to_string()),
+            legendary: None,
+            mythical: None,
+            color: None,
+        };
+        let page = Page {
+            items: Vec::new(),
+            total: 45,
+        };
+
+        let response = PokemonListResponseDto::new(page, &query);
+
+        assert_eq!(response.next, None);
+        assert_eq!(
+            response.prev,
+            Some(
+                "/pokemon?limit=20&offset=40&generation=generation-i&habitat=waters+edge"
+                    .to_string()
+            )
+        );
+    }
+}
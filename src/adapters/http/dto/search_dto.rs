@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::entities::species_index::SearchMatch;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    /// Name or name prefix, in any language PokéAPI has names for.
+    pub q: Option<String>,
+    /// Maximum number of results, 10 by default and at most 50.
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponseDto {
+    query: String,
+    results: Vec<SearchResultDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResultDto {
+    /// Species name, usable as `pokemon_name`.
+    name: String,
+    matched_name: String,
+    /// Language of `matchedName`; absent when the species name matched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl SearchResponseDto {
+    pub fn new(query: String, matches: Vec<SearchMatch>) -> Self {
+        Self {
+            query,
+            results: matches
+                .into_iter()
+                .map(|found| SearchResultDto {
+                    name: found.species,
+                    matched_name: found.matched_name,
+                    language: found.language,
+                })
+                .collect(),
+        }
+    }
+}
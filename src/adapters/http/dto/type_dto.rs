@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::pokemon::Pokemon,
+    services::type_chart::{DefensiveProfile, TypeMatchups, TypeMultiplier},
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeMatchupsResponseDto {
+    name: String,
+    /// Types this type's moves deal other than normal damage to.
+    attacking: Vec<TypeMultiplierDto>,
+    /// Types whose moves deal other than normal damage to this type.
+    defending: Vec<TypeMultiplierDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PokemonWeaknessesResponseDto {
+    name: String,
+    types: Vec<String>,
+    /// Most effective first.
+    weaknesses: Vec<TypeMultiplierDto>,
+    /// Least effective first.
+    resistances: Vec<TypeMultiplierDto>,
+    immunities: Vec<TypeMultiplierDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeMultiplierDto {
+    #[serde(rename = "type")]
+    type_name: String,
+    /// 0, 0.25, 0.5, 2 or 4.
+    multiplier: f32,
+}
+
+impl TypeMatchupsResponseDto {
+    pub fn new(name: &str, matchups: &TypeMatchups) -> Self {
+        Self {
+            name: name.to_string(),
+            attacking: matchups
+                .attacking
+                .iter()
+                .map(TypeMultiplierDto::from)
+                .collect(),
+            defending: matchups
+                .defending
+                .iter()
+                .map(TypeMultiplierDto::from)
+                .collect(),
+        }
+    }
+}
+
+impl PokemonWeaknessesResponseDto {
+    pub fn new(pokemon: &Pokemon, profile: &DefensiveProfile) -> Self {
+        let dtos = |multipliers: Vec<&TypeMultiplier>| {
+            multipliers
+                .into_iter()
+                .map(TypeMultiplierDto::from)
+                .collect()
+        };
+
+        Self {
+            name: pokemon.name().to_string(),
+            types: profile.types.clone(),
+            weaknesses: dtos(profile.weaknesses()),
+            resistances: dtos(profile.resistances()),
+            immunities: dtos(profile.immunities()),
+        }
+    }
+}
+
+impl From<&TypeMultiplier> for TypeMultiplierDto {
+    fn from(multiplier: &TypeMultiplier) -> Self {
+        Self {
+            type_name: multiplier.type_name.clone(),
+            multiplier: multiplier.multiplier,
+        }
+    }
+}
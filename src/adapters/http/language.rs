@@ -0,0 +1,14 @@
+use axum::http::{HeaderMap, header};
+
+use crate::domain::value_objects::language::LanguagePreference;
+
+/// A `lang` query parameter takes precedence over `Accept-Language`.
+pub fn language_preference(lang: Option<&str>, headers: &HeaderMap) -> LanguagePreference {
+    match (lang, headers.get(header::ACCEPT_LANGUAGE)) {
+        (Some(lang), _) => LanguagePreference::single(lang),
+        (None, Some(accept)) => {
+            LanguagePreference::from_accept_language(accept.to_str().unwrap_or_default())
+        }
+        (None, None) => LanguagePreference::default(),
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::adapters::clients::upstream::REQUEST_ID_HEADER;
+use crate::adapters::metrics::Metrics;
+use crate::application::request_id::RequestId;
+
+/// Label for requests that matched no route, so that scanners can't blow up
+/// the number of label values.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Counts and times every request by method, route template and status.
+pub async fn track_requests(
+    State(metrics): State<Arc<Metrics>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(UNMATCHED_ROUTE.to_string(), |path| {
+            path.as_str().to_string()
+        });
+
+    let response = next.run(request).await;
+
+    metrics.record_request(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
+
+/// Takes the client's `X-Request-Id`, or generates one, and makes it the
+/// current request id while the request is served. The id is stored in the
+/// request extensions for the trace span and echoed in the response.
+pub async fn assign_request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(RequestId::parse)
+        .unwrap_or_else(RequestId::generate);
+    request.extensions_mut().insert(id.clone());
+
+    let mut response = id.clone().scope(next.run(request)).await;
+
+    if let Ok(value) = HeaderValue::from_str(id.as_str()) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
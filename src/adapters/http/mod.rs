@@ -1,3 +1,5 @@
 pub mod app_state;
 pub mod dto;
+pub mod language;
+pub mod middleware;
 pub mod routes;
@@ -0,0 +1,40 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::ability_dto::{AbilityResponseDto, EffectQuery};
+use crate::adapters::http::language::language_preference;
+use crate::adapters::openapi::BATTLE_TAG;
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::value_objects::resource_name::ResourceName;
+
+#[utoipa::path(
+    get,
+    path = "/abilities/{ability_name}",
+    tag = BATTLE_TAG,
+    params(
+        EffectQuery,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred effect text languages")
+    ),
+    responses(
+        (status = 200, description = "Ability retrieved successfully", body = AbilityResponseDto),
+        (status = 400, description = "Invalid ability name"),
+        (status = 404, description = "Ability not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn ability_api(
+    Path(ability_name): Path<String>,
+    Query(query): Query<EffectQuery>,
+    headers: HeaderMap,
+    State(state): State<AppState>,
+) -> AppResult<Json<AbilityResponseDto>> {
+    tracing::debug!("Retrieving ability: {}", ability_name);
+
+    let name: ResourceName = ability_name.parse().map_err(AppError::BadRequest)?;
+    let language = language_preference(query.lang.as_deref(), &headers);
+    let ability = state.ability_use_cases.get_ability(name.as_str()).await?;
+
+    Ok(Json(AbilityResponseDto::new(&ability, &language)))
+}
@@ -1,15 +1,44 @@
 use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::routes::abilities::ability_api;
+use crate::adapters::http::routes::evolution::pokemon_evolutions_api;
 use crate::adapters::http::routes::health::health_check;
-use crate::adapters::http::routes::pokemon::{pokemon_api, pokemon_translated_api};
+use crate::adapters::http::routes::metrics::metrics;
+use crate::adapters::http::routes::moves::{move_api, pokemon_moves_api};
+use crate::adapters::http::routes::pokemon::{
+    pokemon_api, pokemon_batch_api, pokemon_flavor_texts_api, pokemon_list_api,
+    pokemon_translated_api,
+};
+use crate::adapters::http::routes::search::pokemon_search_api;
+use crate::adapters::http::routes::types::{pokemon_weaknesses_api, type_matchups_api};
 use axum::Router;
-use axum::routing::get;
+use axum::routing::{get, post};
 
 pub fn api_routes() -> Router<AppState> {
     Router::new()
         .route("/health", get(health_check))
+        .route("/metrics", get(metrics))
+        .route("/pokemon", get(pokemon_list_api))
+        .route("/pokemon/search", get(pokemon_search_api))
+        .route("/pokemon/batch", post(pokemon_batch_api))
         .route("/pokemon/{pokemon_name}", get(pokemon_api))
+        .route(
+            "/pokemon/{pokemon_name}/flavor-texts",
+            get(pokemon_flavor_texts_api),
+        )
+        .route(
+            "/pokemon/{pokemon_name}/evolutions",
+            get(pokemon_evolutions_api),
+        )
+        .route("/pokemon/{pokemon_name}/moves", get(pokemon_moves_api))
+        .route(
+            "/pokemon/{pokemon_name}/weaknesses",
+            get(pokemon_weaknesses_api),
+        )
         .route(
             "/pokemon/translated/{pokemon_name}",
             get(pokemon_translated_api),
         )
+        .route("/types/{type_name}/matchups", get(type_matchups_api))
+        .route("/abilities/{ability_name}", get(ability_api))
+        .route("/moves/{move_name}", get(move_api))
 }
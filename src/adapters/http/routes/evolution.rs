@@ -0,0 +1,34 @@
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::evolution_dto::EvolutionChainResponseDto;
+use crate::adapters::openapi::POKEMON_TAG;
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::value_objects::pokemon_name::PokemonName;
+
+#[utoipa::path(
+    get,
+    path = "/pokemon/{pokemon_name}/evolutions",
+    tag = POKEMON_TAG,
+    responses(
+        (status = 200, description = "Evolution chain retrieved successfully", body = EvolutionChainResponseDto),
+        (status = 400, description = "Invalid Pokemon name"),
+        (status = 404, description = "Pokemon or evolution chain not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_evolutions_api(
+    Path(pokemon_name): Path<String>,
+    State(state): State<AppState>,
+) -> AppResult<Json<EvolutionChainResponseDto>> {
+    tracing::debug!("Retrieving evolution chain: {}", pokemon_name);
+
+    let name: PokemonName = pokemon_name.parse().map_err(AppError::BadRequest)?;
+    let chain = state
+        .evolution_use_cases
+        .get_evolution_chain(name.as_str())
+        .await?;
+
+    Ok(Json(EvolutionChainResponseDto::from(&chain)))
+}
@@ -1,5 +1,11 @@
-use axum::http::StatusCode;
+use axum::Json;
+use axum::extract::State;
 
-pub async fn health_check() -> StatusCode {
-    StatusCode::OK
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::health_dto::HealthResponseDto;
+
+pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponseDto> {
+    Json(HealthResponseDto::new(
+        state.fun_translations_circuit.state().as_str(),
+    ))
 }
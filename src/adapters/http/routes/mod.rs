@@ -1,3 +1,9 @@
+pub(crate) mod abilities;
 pub mod api_routes;
+pub(crate) mod evolution;
 pub mod health;
+pub mod metrics;
+pub(crate) mod moves;
 pub(crate) mod pokemon;
+pub(crate) mod search;
+pub(crate) mod types;
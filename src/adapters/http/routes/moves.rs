@@ -0,0 +1,71 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::ability_dto::EffectQuery;
+use crate::adapters::http::dto::move_dto::{
+    LearnsetQuery, MoveResponseDto, PokemonMovesResponseDto,
+};
+use crate::adapters::http::language::language_preference;
+use crate::adapters::openapi::{BATTLE_TAG, POKEMON_TAG};
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::value_objects::{pokemon_name::PokemonName, resource_name::ResourceName};
+
+#[utoipa::path(
+    get,
+    path = "/moves/{move_name}",
+    tag = BATTLE_TAG,
+    params(
+        EffectQuery,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred effect text languages")
+    ),
+    responses(
+        (status = 200, description = "Move retrieved successfully", body = MoveResponseDto),
+        (status = 400, description = "Invalid move name"),
+        (status = 404, description = "Move not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn move_api(
+    Path(move_name): Path<String>,
+    Query(query): Query<EffectQuery>,
+    headers: HeaderMap,
+    State(state): State<AppState>,
+) -> AppResult<Json<MoveResponseDto>> {
+    tracing::debug!("Retrieving move: {}", move_name);
+
+    let name: ResourceName = move_name.parse().map_err(AppError::BadRequest)?;
+    let language = language_preference(query.lang.as_deref(), &headers);
+    let pokemon_move = state.move_use_cases.get_move(name.as_str()).await?;
+
+    Ok(Json(MoveResponseDto::new(&pokemon_move, &language)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pokemon/{pokemon_name}/moves",
+    tag = POKEMON_TAG,
+    params(LearnsetQuery),
+    responses(
+        (status = 200, description = "Learnset retrieved successfully", body = PokemonMovesResponseDto),
+        (status = 400, description = "Invalid Pokemon name"),
+        (status = 404, description = "Pokemon not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_moves_api(
+    Path(pokemon_name): Path<String>,
+    Query(query): Query<LearnsetQuery>,
+    State(state): State<AppState>,
+) -> AppResult<Json<PokemonMovesResponseDto>> {
+    tracing::debug!("Retrieving pokemon moves: {}", pokemon_name);
+
+    let name: PokemonName = pokemon_name.parse().map_err(AppError::BadRequest)?;
+    let (pokemon, learnset) = state
+        .move_use_cases
+        .get_learnset(name.as_str(), &query.filter())
+        .await?;
+
+    Ok(Json(PokemonMovesResponseDto::new(&pokemon, &learnset)))
+}
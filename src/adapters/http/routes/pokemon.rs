@@ -1,65 +1,193 @@
-use axum::extract::Path;
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, HeaderValue, header};
 
 use crate::adapters::http::app_state::AppState;
-use crate::adapters::http::dto::pokemon_dto::PokemonResponseDto;
+use crate::adapters::http::dto::batch_dto::{BatchRequestDto, BatchResponseDto};
+use crate::adapters::http::dto::pokemon_dto::{
+    FlavorTextsResponseDto, PokemonQuery, PokemonResponseDto, TranslatedPokemonQuery,
+};
+use crate::adapters::http::dto::pokemon_list_dto::{PokemonListQuery, PokemonListResponseDto};
+use crate::adapters::http::language::language_preference;
 use crate::adapters::openapi::POKEMON_TAG;
-use crate::application::app_error::AppResult;
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::value_objects::{
+    language::LanguagePreference, pokemon_lookup::PokemonLookup, pokemon_name::PokemonName,
+    translator::Translator,
+};
 use axum::Json;
 use axum::extract::State;
 
+#[utoipa::path(
+    get,
+    path = "/pokemon",
+    tag = POKEMON_TAG,
+    params(PokemonListQuery),
+    responses(
+        (status = 200, description = "One page of species matching the filters, by national dex number", body = PokemonListResponseDto),
+        (status = 400, description = "Unknown generation"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_list_api(
+    Query(query): Query<PokemonListQuery>,
+    State(state): State<AppState>,
+) -> AppResult<Json<PokemonListResponseDto>> {
+    let filter = query.filter().map_err(AppError::BadRequest)?;
+    tracing::debug!("Listing pokemon: {:?}", filter);
+
+    let page = state
+        .pokemon_use_cases
+        .list_pokemon(&filter, query.offset(), query.limit())
+        .await?;
+
+    Ok(Json(PokemonListResponseDto::new(page, &query)))
+}
+
 #[utoipa::path(
     get,
     path = "/pokemon/{pokemon_name}",
     tag = POKEMON_TAG,
+    params(
+        PokemonQuery,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred description languages, e.g. `fr-CH, fr;q=0.9, en;q=0.8`")
+    ),
     responses(
-        (status = 200, description = "Pokemon retrieved successfully", body = PokemonResponseDto),
+        (status = 200, description = "Pokemon retrieved successfully", body = PokemonResponseDto,
+            headers(("Content-Language" = String, description = "Language of description, genus and displayName; omitted when they differ"))),
+        (status = 400, description = "Invalid Pokemon name, unknown include, or a non-numeric name with `pokedex`"),
+        (status = 404, description = "Pokemon not found, with `suggestions` for close names, or no flavor text for the requested version"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn pokemon_api(
     Path(pokemon_name): Path<String>,
+    Query(query): Query<PokemonQuery>,
+    headers: HeaderMap,
     State(state): State<AppState>,
-) -> AppResult<Json<PokemonResponseDto>> {
+) -> AppResult<(HeaderMap, Json<PokemonResponseDto>)> {
     tracing::debug!("Retrieving pokemon: {}", pokemon_name);
 
-    let pokemon = state
-        .pokemon_use_cases
-        .get_pokemon(pokemon_name.as_str())
-        .await?;
+    let lookup = PokemonLookup::parse(&pokemon_name, query.pokedex.as_deref())
+        .map_err(AppError::BadRequest)?;
+    let includes = query.includes().map_err(AppError::BadRequest)?;
+    let language = language_preference(query.lang.as_deref(), &headers);
+
+    let pokemon = if includes.is_empty() {
+        state
+            .pokemon_use_cases
+            .get_pokemon(&lookup, &language, query.version.as_deref())
+            .await?
+    } else {
+        state
+            .pokemon_use_cases
+            .get_pokemon_details(&lookup, &language, query.version.as_deref())
+            .await?
+    };
 
-    Ok(Json(PokemonResponseDto::new(
-        pokemon.name().to_string(),
-        pokemon.description().to_string(),
-        pokemon.habitat().to_string(),
-        pokemon.is_legendary(),
-    )))
+    let mut response_headers = HeaderMap::new();
+    if let Some(language) = pokemon
+        .content_language()
+        .and_then(|language| HeaderValue::from_str(language).ok())
+    {
+        response_headers.insert(header::CONTENT_LANGUAGE, language);
+    }
+
+    Ok((
+        response_headers,
+        Json(PokemonResponseDto::from(&pokemon).with_includes(&pokemon, &includes)),
+    ))
 }
 
 #[utoipa::path(
     get,
     path = "/pokemon/translated/{pokemon_name}",
     tag = POKEMON_TAG,
+    params(TranslatedPokemonQuery),
     responses(
         (status = 200, description = "Pokemon translated retrieved successfully", body = PokemonResponseDto),
+        (status = 400, description = "Invalid Pokemon name or unknown translation style"),
         (status = 500, description = "Internal server error")
     )
 )]
 #[axum_macros::debug_handler]
 pub async fn pokemon_translated_api(
     Path(pokemon_name): Path<String>,
+    Query(query): Query<TranslatedPokemonQuery>,
     State(state): State<AppState>,
 ) -> AppResult<Json<PokemonResponseDto>> {
     tracing::debug!("Retrieving translated pokemon: {}", pokemon_name);
 
+    let name: PokemonName = pokemon_name.parse().map_err(AppError::BadRequest)?;
+    let translator = query
+        .style
+        .as_deref()
+        .map(str::parse::<Translator>)
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+
+    let pokemon = state
+        .pokemon_use_cases
+        .get_pokemon_translated(name.as_str(), translator)
+        .await?;
+
+    Ok(Json(PokemonResponseDto::from(&pokemon)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/pokemon/batch",
+    tag = POKEMON_TAG,
+    request_body = BatchRequestDto,
+    responses(
+        (status = 200, description = "One result per name, each with its own status", body = BatchResponseDto),
+        (status = 400, description = "Empty batch, or more names than allowed"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_batch_api(
+    State(state): State<AppState>,
+    Json(request): Json<BatchRequestDto>,
+) -> AppResult<Json<BatchResponseDto>> {
+    tracing::debug!("Retrieving pokemon batch: {:?}", request.names);
+
+    if request.names.is_empty() || request.names.len() > state.config.batch_max_size {
+        return Err(AppError::BadRequest(format!(
+            "A batch takes 1 to {} names, got {}",
+            state.config.batch_max_size,
+            request.names.len()
+        )));
+    }
+
+    let results = state
+        .pokemon_use_cases
+        .get_pokemon_batch(request.names.clone(), request.translated)
+        .await;
+
+    Ok(Json(BatchResponseDto::new(request.names, results)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pokemon/{pokemon_name}/flavor-texts",
+    tag = POKEMON_TAG,
+    responses(
+        (status = 200, description = "Flavor texts grouped by language and text", body = FlavorTextsResponseDto),
+        (status = 400, description = "Invalid Pokemon name"),
+        (status = 404, description = "Pokemon not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_flavor_texts_api(
+    Path(pokemon_name): Path<String>,
+    State(state): State<AppState>,
+) -> AppResult<Json<FlavorTextsResponseDto>> {
+    tracing::debug!("Retrieving flavor texts: {}", pokemon_name);
+
+    let lookup = PokemonLookup::parse(&pokemon_name, None).map_err(AppError::BadRequest)?;
     let pokemon = state
         .pokemon_use_cases
-        .get_pokemon_translated(pokemon_name.as_str())
+        .get_pokemon(&lookup, &LanguagePreference::default(), None)
         .await?;
 
-    Ok(Json(PokemonResponseDto::new(
-        pokemon.name().to_string(),
-        pokemon.description().to_string(),
-        pokemon.habitat().to_string(),
-        pokemon.is_legendary(),
-    )))
+    Ok(Json(FlavorTextsResponseDto::from(&pokemon)))
 }
@@ -0,0 +1,42 @@
+use axum::Json;
+use axum::extract::{Query, State};
+
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::search_dto::{SearchQuery, SearchResponseDto};
+use crate::adapters::openapi::POKEMON_TAG;
+use crate::application::app_error::{AppError, AppResult};
+
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+const MAX_SEARCH_LIMIT: usize = 50;
+
+#[utoipa::path(
+    get,
+    path = "/pokemon/search",
+    tag = POKEMON_TAG,
+    params(SearchQuery),
+    responses(
+        (status = 200, description = "Matching species, prefix matches first", body = SearchResponseDto),
+        (status = 400, description = "Missing or empty `q`"),
+        (status = 503, description = "Species index is still being built after startup"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_search_api(
+    Query(query): Query<SearchQuery>,
+    State(state): State<AppState>,
+) -> AppResult<Json<SearchResponseDto>> {
+    let q = query
+        .q
+        .map(|q| q.trim().to_string())
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| AppError::BadRequest("Missing search query `q`".to_string()))?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .min(MAX_SEARCH_LIMIT);
+    tracing::debug!("Searching pokemon: {}", q);
+
+    let matches = state.pokemon_use_cases.search(&q, limit).await?;
+
+    Ok(Json(SearchResponseDto::new(q, matches)))
+}
@@ -0,0 +1,53 @@
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::adapters::http::app_state::AppState;
+use crate::adapters::http::dto::type_dto::{PokemonWeaknessesResponseDto, TypeMatchupsResponseDto};
+use crate::adapters::openapi::{POKEMON_TAG, TYPES_TAG};
+use crate::application::app_error::{AppError, AppResult};
+use crate::domain::value_objects::pokemon_name::PokemonName;
+
+#[utoipa::path(
+    get,
+    path = "/types/{type_name}/matchups",
+    tag = TYPES_TAG,
+    responses(
+        (status = 200, description = "Type matchups retrieved successfully", body = TypeMatchupsResponseDto),
+        (status = 404, description = "Unknown type"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn type_matchups_api(
+    Path(type_name): Path<String>,
+    State(state): State<AppState>,
+) -> AppResult<Json<TypeMatchupsResponseDto>> {
+    tracing::debug!("Retrieving type matchups: {}", type_name);
+
+    let type_name = type_name.trim().to_lowercase();
+    let matchups = state.type_use_cases.get_matchups(&type_name).await?;
+
+    Ok(Json(TypeMatchupsResponseDto::new(&type_name, &matchups)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pokemon/{pokemon_name}/weaknesses",
+    tag = POKEMON_TAG,
+    responses(
+        (status = 200, description = "Defensive multipliers retrieved successfully", body = PokemonWeaknessesResponseDto),
+        (status = 400, description = "Invalid Pokemon name"),
+        (status = 404, description = "Pokemon not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pokemon_weaknesses_api(
+    Path(pokemon_name): Path<String>,
+    State(state): State<AppState>,
+) -> AppResult<Json<PokemonWeaknessesResponseDto>> {
+    tracing::debug!("Retrieving pokemon weaknesses: {}", pokemon_name);
+
+    let name: PokemonName = pokemon_name.parse().map_err(AppError::BadRequest)?;
+    let (pokemon, profile) = state.type_use_cases.get_weaknesses(name.as_str()).await?;
+
+    Ok(Json(PokemonWeaknessesResponseDto::new(&pokemon, &profile)))
+}
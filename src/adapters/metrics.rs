@@ -0,0 +1,250 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use moka::notification::RemovalCause;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+
+/// Upstream calls are slower than requests served from cache, so their buckets
+/// reach further.
+const UPSTREAM_BUCKETS: [f64; 10] = [0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Request, cache and upstream metrics, rendered in the Prometheus text
+/// format by `GET /metrics`. Each app has its own registry.
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    cache_requests: IntCounterVec,
+    cache_evictions: IntCounterVec,
+    upstream_requests: IntCounterVec,
+    upstream_request_duration: HistogramVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let http_labels = ["method", "route", "status"];
+        let upstream_labels = ["client", "endpoint", "outcome"];
+
+        let metrics = Self {
+            registry: Registry::new(),
+            http_requests: IntCounterVec::new(
+                Opts::new("http_requests_total", "HTTP requests handled"),
+                &http_labels,
+            )
+            .unwrap(),
+            http_request_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "http_request_duration_seconds",
+                    "Time taken to handle HTTP requests",
+                ),
+                &http_labels,
+            )
+            .unwrap(),
+            cache_requests: IntCounterVec::new(
+                Opts::new("cache_requests_total", "Cache lookups by result"),
+                &["cache", "result"],
+            )
+            .unwrap(),
+            cache_evictions: IntCounterVec::new(
+                Opts::new(
+                    "cache_evictions_total",
+                    "Entries evicted for expiring or to make room",
+                ),
+                &["cache", "cause"],
+            )
+            .unwrap(),
+            upstream_requests: IntCounterVec::new(
+                Opts::new("upstream_requests_total", "Calls to upstream APIs"),
+                &upstream_labels,
+            )
+            .unwrap(),
+            upstream_request_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "upstream_request_duration_seconds",
+                    "Time taken by upstream API calls, retries included",
+                )
+                .buckets(UPSTREAM_BUCKETS.to_vec()),
+                &upstream_labels,
+            )
+            .unwrap(),
+        };
+
+        for collector in [
+            Box::new(metrics.http_requests.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(metrics.http_request_duration.clone()),
+            Box::new(metrics.cache_requests.clone()),
+            Box::new(metrics.cache_evictions.clone()),
+            Box::new(metrics.upstream_requests.clone()),
+            Box::new(metrics.upstream_request_duration.clone()),
+        ] {
+            metrics.registry.register(collector).unwrap();
+        }
+
+        metrics
+    }
+
+    /// `route` is the matched route template, e.g. `/pokemon/{pokemon_name}`,
+    /// so that label values stay bounded.
+    pub fn record_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// `endpoint` is the PokéAPI resource (`pokemon-species`, `type`, ...) or
+    /// the FunTranslations style.
+    pub fn record_upstream(
+        &self,
+        client: &str,
+        endpoint: &str,
+        outcome: UpstreamOutcome,
+        elapsed: Duration,
+    ) {
+        let labels = [client, endpoint, outcome.as_str()];
+
+        self.upstream_requests.with_label_values(&labels).inc();
+        self.upstream_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn cache(self: &Arc<Self>, name: &'static str) -> CacheMetrics {
+        CacheMetrics {
+            cache: name,
+            metrics: self.clone(),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("Failed to encode metrics");
+        String::from_utf8(buffer).expect("Metrics are valid UTF-8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Metrics of one named cache.
+#[derive(Clone)]
+pub struct CacheMetrics {
+    cache: &'static str,
+    metrics: Arc<Metrics>,
+}
+
+impl CacheMetrics {
+    pub fn name(&self) -> &'static str {
+        self.cache
+    }
+
+    pub fn record_lookup(&self, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        self.metrics
+            .cache_requests
+            .with_label_values(&[self.cache, result])
+            .inc();
+    }
+
+    /// Replaced and explicitly removed entries are not evictions.
+    pub fn record_removal(&self, cause: RemovalCause) {
+        let cause = match cause {
+            RemovalCause::Expired => "expired",
+            RemovalCause::Size => "size",
+            RemovalCause::Explicit | RemovalCause::Replaced => return,
+        };
+        self.metrics
+            .cache_evictions
+            .with_label_values(&[self.cache, cause])
+            .inc();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpstreamOutcome {
+    Success,
+    NotFound,
+    RateLimited,
+    ClientError,
+    ServerError,
+    Timeout,
+    ConnectionError,
+}
+
+impl UpstreamOutcome {
+    pub fn of(result: &reqwest::Result<reqwest::Response>) -> Self {
+        match result {
+            Ok(response) => match response.status() {
+                status if status.is_success() => Self::Success,
+                reqwest::StatusCode::NOT_FOUND => Self::NotFound,
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+                status if status.is_server_error() => Self::ServerError,
+                _ => Self::ClientError,
+            },
+            Err(e) if e.is_timeout() => Self::Timeout,
+            Err(_) => Self::ConnectionError,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::NotFound => "not_found",
+            Self::RateLimited => "rate_limited",
+            Self::ClientError => "client_error",
+            Self::ServerError => "server_error",
+            Self::Timeout => "timeout",
+            Self::ConnectionError => "connection_error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_uses_the_prometheus_text_format() {
+        let metrics = Arc::new(Metrics::new());
+
+        metrics.record_request(
+            "GET",
+            "/pokemon/{pokemon_name}",
+            200,
+            Duration::from_millis(5),
+        );
+        metrics.cache("pokemon").record_lookup(true);
+        metrics.cache("pokemon").record_removal(RemovalCause::Size);
+        metrics
+            .cache("pokemon")
+            .record_removal(RemovalCause::Replaced);
+        metrics.record_upstream(
+            "funtranslations",
+            "yoda",
+            UpstreamOutcome::RateLimited,
+            Duration::from_millis(80),
+        );
+
+        let rendered = metrics.render();
+
+        assert!(rendered.contains(
+            r#"http_requests_total{method="GET",route="/pokemon/{pokemon_name}",status="200"} 1"#
+        ));
+        assert!(rendered.contains(r#"cache_requests_total{cache="pokemon",result="hit"} 1"#));
+        assert!(rendered.contains(r#"cache_evictions_total{cache="pokemon",cause="size"} 1"#));
+        assert!(!rendered.contains(r#"cause="replaced""#));
+        assert!(rendered.contains(
+            r#"upstream_request_duration_seconds_count{client="funtranslations",endpoint="yoda",outcome="rate_limited"} 1"#
+        ));
+    }
+}
@@ -2,5 +2,6 @@ pub mod app_error_impl;
 pub mod cache;
 pub mod clients;
 pub mod http;
+pub mod metrics;
 pub mod openapi;
 pub mod repositories;
@@ -1,8 +1,29 @@
 use utoipa::OpenApi;
 
-use crate::adapters::http::dto::pokemon_dto::PokemonResponseDto;
+use crate::adapters::http::dto::ability_dto::{AbilityHolderDto, AbilityResponseDto, EffectDto};
+use crate::adapters::http::dto::batch_dto::{
+    BatchErrorDto, BatchItemDto, BatchRequestDto, BatchResponseDto,
+};
+use crate::adapters::http::dto::evolution_dto::{
+    EvolutionChainResponseDto, EvolutionConditionDto, EvolutionStageDto,
+};
+use crate::adapters::http::dto::move_dto::{
+    LearnedMoveDto, MoveResponseDto, PokemonMovesResponseDto,
+};
+use crate::adapters::http::dto::pokemon_dto::{
+    AbilityDto, BaseStatsDto, FlavorTextGroupDto, FlavorTextsResponseDto, PokedexNumberDto,
+    PokemonResponseDto, SpritesDto, TranslationMetadataDto, VarietyDto,
+};
+
+use crate::adapters::http::dto::pokemon_list_dto::{PokemonListResponseDto, PokemonSummaryDto};
+use crate::adapters::http::dto::search_dto::{SearchResponseDto, SearchResultDto};
+use crate::adapters::http::dto::type_dto::{
+    PokemonWeaknessesResponseDto, TypeMatchupsResponseDto, TypeMultiplierDto,
+};
 
 pub const POKEMON_TAG: &str = "Pokemon";
+pub const TYPES_TAG: &str = "Types";
+pub const BATTLE_TAG: &str = "Abilities & Moves";
 
 #[derive(OpenApi)]
 #[openapi(
@@ -12,14 +33,53 @@ pub const POKEMON_TAG: &str = "Pokemon";
     ),
     tags(
         (name = POKEMON_TAG, description = "Pokemon APIs"),
+        (name = TYPES_TAG, description = "Type chart APIs"),
+        (name = BATTLE_TAG, description = "Ability and move APIs"),
     ),
     paths(
+        crate::adapters::http::routes::pokemon::pokemon_list_api,
         crate::adapters::http::routes::pokemon::pokemon_api,
-        crate::adapters::http::routes::pokemon::pokemon_translated_api
+        crate::adapters::http::routes::pokemon::pokemon_translated_api,
+        crate::adapters::http::routes::pokemon::pokemon_batch_api,
+        crate::adapters::http::routes::pokemon::pokemon_flavor_texts_api,
+        crate::adapters::http::routes::search::pokemon_search_api,
+        crate::adapters::http::routes::evolution::pokemon_evolutions_api,
+        crate::adapters::http::routes::types::pokemon_weaknesses_api,
+        crate::adapters::http::routes::types::type_matchups_api,
+        crate::adapters::http::routes::moves::pokemon_moves_api,
+        crate::adapters::http::routes::abilities::ability_api,
+        crate::adapters::http::routes::moves::move_api
     ),
     components(
         schemas(PokemonResponseDto),
-        schemas(PokemonResponseDto),
+        schemas(TranslationMetadataDto),
+        schemas(VarietyDto),
+        schemas(BaseStatsDto),
+        schemas(AbilityDto),
+        schemas(SpritesDto),
+        schemas(PokedexNumberDto),
+        schemas(FlavorTextsResponseDto),
+        schemas(FlavorTextGroupDto),
+        schemas(BatchRequestDto),
+        schemas(BatchResponseDto),
+        schemas(BatchItemDto),
+        schemas(BatchErrorDto),
+        schemas(PokemonListResponseDto),
+        schemas(PokemonSummaryDto),
+        schemas(SearchResponseDto),
+        schemas(SearchResultDto),
+        schemas(EvolutionChainResponseDto),
+        schemas(EvolutionStageDto),
+        schemas(EvolutionConditionDto),
+        schemas(TypeMatchupsResponseDto),
+        schemas(PokemonWeaknessesResponseDto),
+        schemas(TypeMultiplierDto),
+        schemas(PokemonMovesResponseDto),
+        schemas(LearnedMoveDto),
+        schemas(AbilityResponseDto),
+        schemas(AbilityHolderDto),
+        schemas(EffectDto),
+        schemas(MoveResponseDto),
    )
 )]
 pub struct ApiDoc;
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::{
+        cache::typed_cache::TypedCache,
+        clients::{dto::ability_dto::VerboseEffectDto, pokeapi::PokeApiClient},
+    },
+    application::{app_error::AppResult, traits::AbilityRepository},
+    domain::{
+        entities::ability_details::{AbilityDetails, AbilityHolder},
+        value_objects::effect_text::EffectText,
+    },
+};
+
+pub struct AbilityRepositoryImpl {
+    pokeapi_client: Arc<PokeApiClient>,
+    cache: TypedCache<String, AbilityDetails>,
+}
+
+impl AbilityRepositoryImpl {
+    pub fn new(
+        pokeapi_client: Arc<PokeApiClient>,
+        cache: TypedCache<String, AbilityDetails>,
+    ) -> Self {
+        Self {
+            pokeapi_client,
+            cache,
+        }
+    }
+
+    async fn fetch(&self, name: &str) -> AppResult<AbilityDetails> {
+        let ability = self.pokeapi_client.ability(name).await?;
+
+        Ok(AbilityDetails::new(
+            ability.name,
+            map_effects(&ability.effect_entries),
+            ability
+                .pokemon
+                .into_iter()
+                .map(|holder| AbilityHolder {
+                    name: holder.pokemon.name,
+                    is_hidden: holder.is_hidden,
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[async_trait]
+impl AbilityRepository for AbilityRepositoryImpl {
+    async fn get_ability<'a>(&'a self, name: &'a str) -> AppResult<AbilityDetails> {
+        let lookup = self
+            .cache
+            .get_or_try_insert_with(name.to_string(), self.fetch(name))
+            .await
+            .map_err(|e| (*e).clone())?;
+
+        Ok(lookup.value)
+    }
+}
+
+pub(crate) fn map_effects(effects: &[VerboseEffectDto]) -> Vec<EffectText> {
+    effects
+        .iter()
+        .map(|entry| EffectText {
+            language: entry.language.name.clone(),
+            effect: entry.effect.clone(),
+            short_effect: entry.short_effect.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::cache::typed_cache::TypedCache,
+    application::{
+        app_error::{AppError, AppResult},
+        traits::PokemonRepository,
+    },
+    domain::{
+        entities::pokemon::Pokemon,
+        value_objects::{species_rarity::SpeciesRarity, species_summary::SpeciesSummary},
+    },
+};
+
+/// Caching decorator around any `PokemonRepository`.
+///
+/// Successful lookups are kept in `cache`; `NotFound` errors are remembered in
+/// `not_found` (usually with a much shorter TTL) so repeated typos don't reach
+/// the upstream either, under the same key as the lookup that failed. Other
+/// errors are never cached. Concurrent lookups of
+/// the same uncached name share a single upstream call. Detailed lookups are
+/// cached separately from species-only ones, and the species catalog is kept
+/// in `catalog`. Every species looked up also has its rarity kept in
+/// `rarity`, which should hold every species so listing by rarity never
+/// looks one up twice.
+pub struct CachedPokemonRepository {
+    inner: Arc<dyn PokemonRepository>,
+    cache: TypedCache<String, Pokemon>,
+    not_found: TypedCache<String, String>,
+    catalog: TypedCache<(), Vec<SpeciesSummary>>,
+    rarity: TypedCache<String, SpeciesRarity>,
+}
+
+impl CachedPokemonRepository {
+    pub fn new(
+        inner: Arc<dyn PokemonRepository>,
+        cache: TypedCache<String, Pokemon>,
+        not_found: TypedCache<String, String>,
+        catalog: TypedCache<(), Vec<SpeciesSummary>>,
+        rarity: TypedCache<String, SpeciesRarity>,
+    ) -> Self {
+        Self {
+            inner,
+            cache,
+            not_found,
+            catalog,
+            rarity,
+        }
+    }
+}
+
+impl CachedPokemonRepository {
+    async fn cached_lookup<'a>(
+        &'a self,
+        cache_key: String,
+        name: &'a str,
+        init: impl Future<Output = AppResult<Pokemon>>,
+    ) -> AppResult<Pokemon> {
+        if let Some(message) = self.not_found.get(&cache_key).await {
+            tracing::info!(
+                pokemon = name,
+                cache = "pokemon_not_found",
+                cache_status = "hit",
+                "Pokemon negative cache hit"
+            );
+            return Err(AppError::NotFound(message));
+        }
+
+        match self
+            .cache
+            .get_or_try_insert_with(cache_key.clone(), init)
+            .await
+        {
+            Ok(lookup) => {
+                if !lookup.from_cache {
+                    self.rarity
+                        .insert(lookup.value.name().to_string(), lookup.value.rarity())
+                        .await;
+                }
+                Ok(lookup.value)
+            }
+            Err(e) => match (*e).clone() {
+                AppError::NotFound(message) => {
+                    self.not_found.insert(cache_key, message.clone()).await;
+                    Err(AppError::NotFound(message))
+                }
+                e => Err(e),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl PokemonRepository for CachedPokemonRepository {
+    async fn get_pokemon<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
+        self.cached_lookup(name.to_string(), name, self.inner.get_pokemon(name))
+            .await
+    }
+
+    async fn get_pokemon_details<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
+        self.cached_lookup(
+            format!("{}+details", name),
+            name,
+            self.inner.get_pokemon_details(name),
+        )
+        .await
+    }
+
+    async fn get_pokemon_by_pokedex_number<'a>(
+        &'a self,
+        pokedex: &'a str,
+        number: u32,
+    ) -> AppResult<Pokemon> {
+        let key = format!("{}#{}", pokedex, number);
+        self.cached_lookup(
+            key.clone(),
+            &key,
+            self.inner.get_pokemon_by_pokedex_number(pokedex, number),
+        )
+        .await
+    }
+
+    async fn get_species_catalog(&self) -> AppResult<Vec<SpeciesSummary>> {
+        let lookup = self
+            .catalog
+            .get_or_try_insert_with((), self.inner.get_species_catalog())
+            .await
+            .map_err(|e| (*e).clone())?;
+
+        Ok(lookup.value)
+    }
+
+    async fn refresh_species_catalog(&self) -> AppResult<()> {
+        let catalog = self.inner.get_species_catalog().await?;
+        self.catalog.insert((), catalog).await;
+        Ok(())
+    }
+
+    async fn get_species_rarity<'a>(&'a self, name: &'a str) -> AppResult<SpeciesRarity> {
+        if let Some(rarity) = self.rarity.get(name).await {
+            return Ok(rarity);
+        }
+        let rarity = self.get_pokemon(name).await?.rarity();
+        self.rarity.insert(name.to_string(), rarity).await;
+        Ok(rarity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::traits::MockPokemonRepository;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    struct SlowPokemonRepository {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PokemonRepository for SlowPokemonRepository {
+        async fn get_pokemon<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            Ok(Pokemon::new(
+                name.to_string(),
+                "Genetic clone".to_string(),
+                "Rare".to_string(),
+                true,
+            ))
+        }
+
+        async fn get_pokemon_details<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
+            self.get_pokemon(name).await
+        }
+
+        async fn get_pokemon_by_pokedex_number<'a>(
+            &'a self,
+            _pokedex: &'a str,
+            number: u32,
+        ) -> AppResult<Pokemon> {
+            self.get_pokemon(&number.to_string()).await
+        }
+
+        async fn get_species_catalog(&self) -> AppResult<Vec<SpeciesSummary>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn cached(inner: MockPokemonRepository) -> CachedPokemonRepository {
+        CachedPokemonRepository::new(
+            Arc::new(inner),
+            TypedCache::new(100, Duration::from_secs(60), None),
+            TypedCache::new(100, Duration::from_secs(60), None),
+            TypedCache::new(1, Duration::from_secs(60), None),
+            TypedCache::new(100, Duration::from_secs(60), None),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_found_pokemon_is_served_from_cache() {
+        let mut inner = MockPokemonRepository::new();
+        inner.expect_get_pokemon().times(1).returning(|_| {
+            Ok(Pokemon::new(
+                "pikachu".to_string(),
+                "Electric mouse".to_string(),
+                "Forest".to_string(),
+                false,
+            ))
+        });
+
+        let repository = cached(inner);
+
+        assert_eq!(
+            repository.get_pokemon("pikachu").await.unwrap().name(),
+            "pikachu"
+        );
+        assert_eq!(
+            repository.get_pokemon("pikachu").await.unwrap().name(),
+            "pikachu"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_not_found_is_negatively_cached() {
+        let mut inner = MockPokemonRepository::new();
+        inner
+            .expect_get_pokemon()
+            .times(1)
+            .returning(|_| Err(AppError::NotFound("missingno".to_string())));
+
+        let repository = cached(inner);
+
+        assert!(matches!(
+            repository.get_pokemon("missingno").await,
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            repository.get_pokemon("missingno").await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_other_errors_are_not_cached() {
+        let mut inner = MockPokemonRepository::new();
+        inner
+            .expect_get_pokemon()
+            .times(2)
+            .returning(|_| Err(AppError::GenericError("upstream down".to_string())));
+
+        let repository = cached(inner);
+
+        assert!(repository.get_pokemon("pikachu").await.is_err());
+        assert!(repository.get_pokemon("pikachu").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_lookups_share_a_single_upstream_call() {
+        let inner = Arc::new(SlowPokemonRepository {
+            calls: AtomicUsize::new(0),
+        });
+        let repository = Arc::new(CachedPokemonRepository::new(
+            inner.clone(),
+            TypedCache::new(100, Duration::from_secs(60), None),
+            TypedCache::new(100, Duration::from_secs(60), None),
+            TypedCache::new(1, Duration::from_secs(60), None),
+            TypedCache::new(100, Duration::from_secs(60), None),
+        ));
+
+        let handles: Vec<_> = (0..50)
+            .map(|_| {
+                let repository = repository.clone();
+                tokio::spawn(async move { repository.get_pokemon("mewtwo").await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap().name(), "mewtwo");
+        }
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_detailed_lookups_are_cached_separately() {
+        let mut inner = MockPokemonRepository::new();
+        inner.expect_get_pokemon().times(1).returning(|_| {
+            Ok(Pokemon::new(
+                "pikachu".to_string(),
+                "Electric mouse".to_string(),
+                "Forest".to_string(),
+                false,
+            ))
+        });
+        inner.expect_get_pokemon_details().times(1).returning(|_| {
+            Ok(Pokemon::new(
+                "pikachu".to_string(),
+                "Electric mouse".to_string(),
+                "Forest".to_string(),
+                false,
+            )
+            .with_types(vec!["electric".to_string()]))
+        });
+
+        let repository = cached(inner);
+
+        assert!(
+            repository
+                .get_pokemon("pikachu")
+                .await
+                .unwrap()
+                .types()
+                .is_empty()
+        );
+        for _ in 0..2 {
+            assert_eq!(
+                repository
+                    .get_pokemon_details("pikachu")
+                    .await
+                    .unwrap()
+                    .types(),
+                ["electric".to_string()]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_details_not_found_does_not_poison_species_lookup() {
+        let mut inner = MockPokemonRepository::new();
+        inner
+            .expect_get_pokemon_details()
+            .times(1)
+            .returning(|_| Err(AppError::NotFound("no default form".to_string())));
+        inner.expect_get_pokemon().times(1).returning(|name| {
+            Ok(Pokemon::new(
+                name.to_string(),
+                "Shape-shifting".to_string(),
+                "Rare".to_string(),
+                false,
+            ))
+        });
+
+        let repository = cached(inner);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                repository.get_pokemon_details("deoxys").await,
+                Err(AppError::NotFound(_))
+            ));
+            assert_eq!(
+                repository.get_pokemon("deoxys").await.unwrap().name(),
+                "deoxys"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rarity_is_remembered_from_any_lookup() {
+        let mut inner = MockPokemonRepository::new();
+        inner.expect_get_pokemon().times(1).returning(|name| {
+            Ok(Pokemon::new(
+                name.to_string(),
+                "Genetic clone".to_string(),
+                "rare".to_string(),
+                true,
+            ))
+        });
+
+        let repository = cached(inner);
+
+        repository.get_pokemon("mewtwo").await.unwrap();
+        for _ in 0..2 {
+            assert_eq!(
+                repository.get_species_rarity("mewtwo").await.unwrap(),
+                SpeciesRarity {
+                    is_legendary: true,
+                    is_mythical: false,
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refreshed_catalog_is_served_from_cache() {
+        let mut inner = MockPokemonRepository::new();
+        inner.expect_get_species_catalog().times(1).returning(|| {
+            Ok(vec![SpeciesSummary {
+                id: 25,
+                name: "pikachu".to_string(),
+                generation: None,
+                habitat: None,
+                color: None,
+            }])
+        });
+
+        let repository = cached(inner);
+
+        repository.refresh_species_catalog().await.unwrap();
+        assert_eq!(repository.get_species_catalog().await.unwrap().len(), 1);
+    }
+}
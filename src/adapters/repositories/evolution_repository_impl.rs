@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::{
+        cache::typed_cache::TypedCache,
+        clients::{
+            dto::{
+                evolution_chain_dto::{ChainLinkDto, EvolutionDetailDto},
+                pokemon_species_dto::NamedApiResource,
+            },
+            pokeapi::PokeApiClient,
+        },
+    },
+    application::{app_error::AppResult, traits::EvolutionRepository},
+    domain::{
+        entities::evolution_chain::{EvolutionChain, EvolutionStage},
+        value_objects::evolution_condition::{EvolutionCondition, EvolutionTrigger},
+    },
+};
+
+/// Evolution chains are shared by every species in them, so they are cached
+/// by chain id rather than by species name.
+pub struct EvolutionRepositoryImpl {
+    pokeapi_client: Arc<PokeApiClient>,
+    cache: TypedCache<u32, EvolutionChain>,
+}
+
+impl EvolutionRepositoryImpl {
+    pub fn new(pokeapi_client: Arc<PokeApiClient>, cache: TypedCache<u32, EvolutionChain>) -> Self {
+        Self {
+            pokeapi_client,
+            cache,
+        }
+    }
+
+    async fn fetch(&self, id: u32) -> AppResult<EvolutionChain> {
+        let chain = self.pokeapi_client.evolution_chain(id).await?;
+        Ok(EvolutionChain::new(chain.id, map_stage(&chain.chain)))
+    }
+}
+
+#[async_trait]
+impl EvolutionRepository for EvolutionRepositoryImpl {
+    async fn get_evolution_chain(&self, id: u32) -> AppResult<EvolutionChain> {
+        let lookup = self
+            .cache
+            .get_or_try_insert_with(id, self.fetch(id))
+            .await
+            .map_err(|e| (*e).clone())?;
+
+        Ok(lookup.value)
+    }
+}
+
+fn map_stage(link: &ChainLinkDto) -> EvolutionStage {
+    EvolutionStage::new(
+        link.species.name.clone(),
+        link.is_baby,
+        link.evolution_details.iter().map(map_condition).collect(),
+        link.evolves_to.iter().map(map_stage).collect(),
+    )
+}
+
+fn map_condition(detail: &EvolutionDetailDto) -> EvolutionCondition {
+    let name = |resource: &Option<NamedApiResource>| resource.as_ref().map(|r| r.name.clone());
+
+    EvolutionCondition {
+        min_level: detail.min_level,
+        item: name(&detail.item),
+        held_item: name(&detail.held_item),
+        known_move: name(&detail.known_move),
+        known_move_type: name(&detail.known_move_type),
+        location: name(&detail.location),
+        min_happiness: detail.min_happiness,
+        min_beauty: detail.min_beauty,
+        min_affection: detail.min_affection,
+        time_of_day: Some(detail.time_of_day.clone()).filter(|time| !time.is_empty()),
+        gender: detail.gender,
+        needs_overworld_rain: detail.needs_overworld_rain,
+        party_species: name(&detail.party_species),
+        party_type: name(&detail.party_type),
+        relative_physical_stats: detail.relative_physical_stats,
+        trade_species: name(&detail.trade_species),
+        turn_upside_down: detail.turn_upside_down,
+        ..EvolutionCondition::new(
+            detail
+                .trigger
+                .as_ref()
+                .map_or(EvolutionTrigger::Other(String::new()), |trigger| {
+                    EvolutionTrigger::from_name(&trigger.name)
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_stage_builds_nested_tree() {
+        let chain: ChainLinkDto = serde_json::from_value(serde_json::json!({
+            "species": { "name": "eevee", "url": "" },
+            "is_baby": false,
+            "evolution_details": [],
+            "evolves_to": [{
+                "species": { "name": "umbreon", "url": "" },
+                "is_baby": false,
+                "evolution_details": [{
+                    "trigger": { "name": "level-up", "url": "" },
+                    "min_happiness": 160,
+                    "time_of_day": "night"
+                }],
+                "evolves_to": []
+            }]
+        }))
+        .unwrap();
+
+        let root = map_stage(&chain);
+        let umbreon = &root.evolves_to()[0];
+        let condition = &umbreon.conditions()[0];
+
+        assert_eq!(root.species(), "eevee");
+        assert!(root.conditions().is_empty());
+        assert_eq!(umbreon.species(), "umbreon");
+        assert_eq!(condition.trigger, EvolutionTrigger::LevelUp);
+        assert_eq!(condition.min_happiness, Some(160));
+        assert_eq!(condition.time_of_day.as_deref(), Some("night"));
+        assert_eq!(condition.min_level, None);
+    }
+}
@@ -1,2 +1,8 @@
+pub mod ability_repository_impl;
+pub mod cached_pokemon_repository;
+pub mod evolution_repository_impl;
+pub mod move_repository_impl;
 pub mod pokemon_repository_impl;
+pub mod species_index_repository_impl;
 pub mod translation_repository_impl;
+pub mod type_repository_impl;
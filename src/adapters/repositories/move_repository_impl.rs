@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::{
+        cache::typed_cache::TypedCache, clients::pokeapi::PokeApiClient,
+        repositories::ability_repository_impl::map_effects,
+    },
+    application::{app_error::AppResult, traits::MoveRepository},
+    domain::entities::move_details::{MoveDetails, MoveStats},
+};
+
+pub struct MoveRepositoryImpl {
+    pokeapi_client: Arc<PokeApiClient>,
+    cache: TypedCache<String, MoveDetails>,
+}
+
+impl MoveRepositoryImpl {
+    pub fn new(pokeapi_client: Arc<PokeApiClient>, cache: TypedCache<String, MoveDetails>) -> Self {
+        Self {
+            pokeapi_client,
+            cache,
+        }
+    }
+
+    async fn fetch(&self, name: &str) -> AppResult<MoveDetails> {
+        let pokemon_move = self.pokeapi_client.pokemon_move(name).await?;
+
+        Ok(MoveDetails::new(
+            pokemon_move.name,
+            pokemon_move.move_type.name,
+            pokemon_move.damage_class.name,
+            MoveStats {
+                power: pokemon_move.power,
+                accuracy: pokemon_move.accuracy,
+                pp: pokemon_move.pp,
+                priority: pokemon_move.priority,
+                effect_chance: pokemon_move.effect_chance,
+            },
+            map_effects(&pokemon_move.effect_entries),
+        ))
+    }
+}
+
+#[async_trait]
+impl MoveRepository for MoveRepositoryImpl {
+    async fn get_move<'a>(&'a self, name: &'a str) -> AppResult<MoveDetails> {
+        let lookup = self
+            .cache
+            .get_or_try_insert_with(name.to_string(), self.fetch(name))
+            .await
+            .map_err(|e| (*e).clone())?;
+
+        Ok(lookup.value)
+    }
+}
@@ -1,13 +1,43 @@
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
 use crate::{
-    adapters::clients::pokeapi::PokeApiClient,
-    application::{app_error::AppResult, traits::PokemonRepository},
-    domain::entities::pokemon::Pokemon,
+    adapters::clients::{
+        dto::{
+            pokemon_details_dto::PokemonDetailsResponseDto,
+            pokemon_species_dto::PokemonSpeciesResponseDto,
+        },
+        pokeapi::PokeApiClient,
+    },
+    application::{
+        app_error::{AppError, AppResult},
+        concurrency::map_bounded,
+        traits::PokemonRepository,
+    },
+    domain::{
+        entities::pokemon::Pokemon,
+        value_objects::{
+            ability::Ability,
+            base_stats::BaseStats,
+            egg_group::EggGroup,
+            generation::Generation,
+            growth_rate::GrowthRate,
+            learned_move::LearnedMove,
+            localized_text::{FlavorText, LocalizedText},
+            pokedex_number::PokedexNumber,
+            species_summary::SpeciesSummary,
+            sprites::Sprites,
+            variety::Variety,
+        },
+    },
 };
 
+/// Generations, habitats and colors each number about ten.
+const GROUP_FETCH_CONCURRENCY: usize = 4;
+
 pub struct PokemonRepositoryImpl {
     pokeapi_client: Arc<PokeApiClient>,
 }
@@ -21,16 +51,284 @@ impl PokemonRepositoryImpl {
 #[async_trait]
 impl PokemonRepository for PokemonRepositoryImpl {
     async fn get_pokemon<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
-        let pokemon = self.pokeapi_client.pokemon_species(name).await?;
-        Ok(Pokemon::new(
-            pokemon.name.clone(),
+        let (species, variety) = self.find_species(name).await?;
+        Ok(with_variety(map_species(&species), variety.as_ref()))
+    }
+
+    async fn get_pokemon_details<'a>(&'a self, name: &'a str) -> AppResult<Pokemon> {
+        let (species, variety) = self.find_species(name).await?;
+        let pokemon = with_variety(map_species(&species), variety.as_ref());
+
+        let details = match variety {
+            Some(details) => details,
+            None => {
+                // Species and Pokemon names differ for species whose default
+                // form has its own name (e.g. `deoxys` -> `deoxys-normal`).
+                let default_variety = species
+                    .varieties
+                    .iter()
+                    .find(|variety| variety.is_default)
+                    .map_or(species.name.as_str(), |variety| &variety.pokemon.name);
+                self.pokeapi_client.pokemon(default_variety).await?
+            }
+        };
+
+        Ok(merge_details(pokemon, &details))
+    }
+
+    async fn get_pokemon_by_pokedex_number<'a>(
+        &'a self,
+        pokedex: &'a str,
+        number: u32,
+    ) -> AppResult<Pokemon> {
+        let entries = self.pokeapi_client.pokedex(pokedex).await?;
+        let name = entries.get_species(number).ok_or_else(|| {
+            AppError::NotFound(format!("No entry {} in pokedex '{}'", number, pokedex))
+        })?;
+
+        let species = self.pokeapi_client.pokemon_species(name).await?;
+        Ok(map_species(&species))
+    }
+
+    async fn get_species_catalog(&self) -> AppResult<Vec<SpeciesSummary>> {
+        let (species, generations, habitats, colors) = tokio::try_join!(
+            self.pokeapi_client.pokemon_species_list(),
+            self.species_groups("generation"),
+            self.species_groups("pokemon-habitat"),
+            self.species_groups("pokemon-color"),
+        )?;
+
+        let mut catalog: Vec<SpeciesSummary> = species
+            .results
+            .into_iter()
+            .filter_map(|species| {
+                Some(SpeciesSummary {
+                    id: species.id()?,
+                    generation: generations
+                        .get(&species.name)
+                        .and_then(|generation| parse_or_warn(generation)),
+                    habitat: habitats.get(&species.name).cloned(),
+                    color: colors.get(&species.name).cloned(),
+                    name: species.name,
+                })
+            })
+            .collect();
+        catalog.sort_by_key(|species| species.id);
+
+        Ok(catalog)
+    }
+}
+
+impl PokemonRepositoryImpl {
+    /// Maps every species name to the `resource` group (e.g. the
+    /// `pokemon-color`) it belongs to.
+    async fn species_groups(&self, resource: &str) -> AppResult<HashMap<String, String>> {
+        let groups = self.pokeapi_client.resource_list(resource).await?;
+        let client = self.pokeapi_client.clone();
+        let resource = resource.to_string();
+
+        let groups = map_bounded(groups.results, GROUP_FETCH_CONCURRENCY, move |group| {
+            let client = client.clone();
+            let resource = resource.clone();
+            async move { client.species_group(&resource, &group.name).await }
+        })
+        .await;
+
+        let mut species_groups = HashMap::new();
+        for group in groups {
+            let group = group?;
+            for species in group.pokemon_species {
+                species_groups.insert(species.name, group.name.clone());
+            }
+        }
+        Ok(species_groups)
+    }
+
+    /// Looks `name` up as a species, then as a variety (`giratina-origin`),
+    /// returning the variety's details in the latter case.
+    async fn find_species(
+        &self,
+        name: &str,
+    ) -> AppResult<(PokemonSpeciesResponseDto, Option<PokemonDetailsResponseDto>)> {
+        let message = match self.pokeapi_client.pokemon_species(name).await {
+            Err(AppError::NotFound(message)) => message,
+            result => return result.map(|species| (species, None)),
+        };
+
+        let variety = match self.pokeapi_client.pokemon(name).await {
+            Err(AppError::NotFound(_)) => return Err(AppError::NotFound(message)),
+            result => result?,
+        };
+        let species = self
+            .pokeapi_client
+            .pokemon_species(&variety.species.name)
+            .await?;
+
+        Ok((species, Some(variety)))
+    }
+}
+
+fn with_variety(pokemon: Pokemon, variety: Option<&PokemonDetailsResponseDto>) -> Pokemon {
+    match variety {
+        Some(variety) => pokemon.with_variety(variety.name.clone()),
+        None => pokemon,
+    }
+}
+
+fn map_species(pokemon: &PokemonSpeciesResponseDto) -> Pokemon {
+    let mut result = Pokemon::new(
+        pokemon.name.clone(),
+        pokemon
+            .get_english_description()
+            .unwrap_or_else(|| "Description not available".to_string()),
+        pokemon
+            .get_habitat()
+            .unwrap_or_else(|| "Description not available".to_string()),
+        pokemon.is_legendary,
+    )
+    .with_mythical(pokemon.is_mythical)
+    .with_baby(pokemon.is_baby)
+    .with_color(pokemon.color.name.clone())
+    .with_shape(pokemon.shape.name.clone())
+    .with_egg_groups(
+        pokemon
+            .egg_groups
+            .iter()
+            .filter_map(|group| parse_or_warn::<EggGroup>(&group.name))
+            .collect(),
+    )
+    .with_varieties(
+        pokemon
+            .varieties
+            .iter()
+            .map(|variety| Variety {
+                name: variety.pokemon.name.clone(),
+                is_default: variety.is_default,
+            })
+            .collect(),
+    );
+
+    result = result.with_pokedex_numbers(
+        pokemon
+            .pokedex_numbers
+            .iter()
+            .filter_map(|entry| {
+                Some(PokedexNumber {
+                    pokedex: entry.pokedex.name.clone(),
+                    entry_number: u32::try_from(entry.entry_number).ok()?,
+                })
+            })
+            .collect(),
+    );
+    if let Ok(id) = u32::try_from(pokemon.id) {
+        result = result.with_id(id);
+    }
+    if let Ok(capture_rate) = u8::try_from(pokemon.capture_rate) {
+        result = result.with_capture_rate(capture_rate);
+    }
+    if let Some(generation) = parse_or_warn::<Generation>(&pokemon.generation.name) {
+        result = result.with_generation(generation);
+    }
+    if let Some(growth_rate) = parse_or_warn::<GrowthRate>(&pokemon.growth_rate.name) {
+        result = result.with_growth_rate(growth_rate);
+    }
+    result = result
+        .with_names(
+            pokemon
+                .names
+                .iter()
+                .map(|name| LocalizedText {
+                    language: name.language.name.clone(),
+                    text: name.name.clone(),
+                })
+                .collect(),
+        )
+        .with_genera(
             pokemon
-                .get_english_description()
-                .unwrap_or_else(|| "Description not available".to_string()),
+                .genera
+                .iter()
+                .map(|genus| LocalizedText {
+                    language: genus.language.name.clone(),
+                    text: genus.genus.clone(),
+                })
+                .collect(),
+        )
+        .with_flavor_texts(
             pokemon
-                .get_habitat()
-                .unwrap_or_else(|| "Description not available".to_string()),
-            pokemon.is_legendary,
-        ))
+                .flavor_text_entries
+                .iter()
+                .map(|entry| FlavorText {
+                    language: entry.language.name.clone(),
+                    version: entry.version.name.clone(),
+                    text: entry.clean_text(),
+                })
+                .collect(),
+        );
+    if let Some(genus) = pokemon.get_english_genus() {
+        result = result.with_genus(genus);
+    }
+    if let Some(evolution_chain_id) = pokemon.get_evolution_chain_id() {
+        result = result.with_evolution_chain_id(evolution_chain_id);
+    }
+    if let Some(evolves_from) = &pokemon.evolves_from_species {
+        result = result.with_evolves_from(evolves_from.name.clone());
     }
+
+    result
+}
+
+fn merge_details(pokemon: Pokemon, details: &PokemonDetailsResponseDto) -> Pokemon {
+    pokemon
+        .with_types(details.get_types())
+        .with_stats(BaseStats {
+            hp: details.get_base_stat("hp"),
+            attack: details.get_base_stat("attack"),
+            defense: details.get_base_stat("defense"),
+            special_attack: details.get_base_stat("special-attack"),
+            special_defense: details.get_base_stat("special-defense"),
+            speed: details.get_base_stat("speed"),
+        })
+        .with_abilities(
+            details
+                .abilities
+                .iter()
+                .map(|ability| Ability {
+                    name: ability.ability.name.clone(),
+                    is_hidden: ability.is_hidden,
+                    slot: ability.slot,
+                })
+                .collect(),
+        )
+        .with_learnset(
+            details
+                .moves
+                .iter()
+                .flat_map(|learned| {
+                    learned
+                        .version_group_details
+                        .iter()
+                        .map(|version| LearnedMove {
+                            name: learned.pokemon_move.name.clone(),
+                            method: version.move_learn_method.name.clone(),
+                            level: (version.level_learned_at > 0)
+                                .then_some(version.level_learned_at),
+                            version_group: version.version_group.name.clone(),
+                        })
+                })
+                .collect(),
+        )
+        .with_size(details.height, details.weight)
+        .with_sprites(Sprites {
+            front_default: details.sprites.front_default.clone(),
+            front_shiny: details.sprites.front_shiny.clone(),
+        })
+}
+
+/// PokéAPI occasionally adds new values; an unknown one is dropped rather than
+/// failing the whole lookup.
+fn parse_or_warn<T: FromStr<Err = String>>(value: &str) -> Option<T> {
+    value
+        .parse()
+        .inspect_err(|e| tracing::warn!("Ignoring PokéAPI value: {}", e))
+        .ok()
 }
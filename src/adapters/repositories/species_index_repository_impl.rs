@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::cache::typed_cache::TypedCache,
+    application::{
+        app_error::AppResult,
+        concurrency::map_bounded,
+        traits::{PokemonRepository, SpeciesIndexRepository},
+    },
+    domain::entities::species_index::{IndexedSpecies, SpeciesIndex},
+};
+
+/// Species looked up at once while building the index, about a thousand in
+/// total.
+const SPECIES_LOOKUP_CONCURRENCY: usize = 8;
+
+/// Builds the species index by looking every species of the catalog up for
+/// its localized names, and keeps it for the `cache` TTL. The index is only
+/// built by `refresh_species_index`, never on a read. Lookups go through
+/// `pokemon_repository`, so they fill its caches too; species that fail to
+/// load are left out.
+pub struct SpeciesIndexRepositoryImpl {
+    pokemon_repository: Arc<dyn PokemonRepository>,
+    cache: TypedCache<(), Arc<SpeciesIndex>>,
+}
+
+impl SpeciesIndexRepositoryImpl {
+    pub fn new(
+        pokemon_repository: Arc<dyn PokemonRepository>,
+        cache: TypedCache<(), Arc<SpeciesIndex>>,
+    ) -> Self {
+        Self {
+            pokemon_repository,
+            cache,
+        }
+    }
+
+    async fn fetch(&self) -> AppResult<Arc<SpeciesIndex>> {
+        let catalog = self.pokemon_repository.get_species_catalog().await?;
+        let repository = self.pokemon_repository.clone();
+        let lookups = map_bounded(catalog, SPECIES_LOOKUP_CONCURRENCY, move |species| {
+            let repository = repository.clone();
+            async move { (repository.get_pokemon(&species.name).await, species.name) }
+        })
+        .await;
+
+        let mut species = Vec::with_capacity(lookups.len());
+        for (pokemon, name) in lookups {
+            match pokemon {
+                Ok(pokemon) => species.push(IndexedSpecies {
+                    name,
+                    names: pokemon.names().to_vec(),
+                }),
+                Err(e) => tracing::warn!(
+                    pokemon = name,
+                    error = %e,
+                    "Leaving species out of the index"
+                ),
+            }
+        }
+
+        tracing::info!(species = species.len(), "Built species index");
+        Ok(Arc::new(SpeciesIndex::new(species)))
+    }
+}
+
+#[async_trait]
+impl SpeciesIndexRepository for SpeciesIndexRepositoryImpl {
+    async fn get_species_index(&self) -> Option<Arc<SpeciesIndex>> {
+        self.cache.get(&()).await
+    }
+
+    async fn refresh_species_index(&self) -> AppResult<()> {
+        let index = self.fetch().await?;
+        self.cache.insert((), index).await;
+        Ok(())
+    }
+}
@@ -1,62 +1,147 @@
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use crate::adapters::cache::translation_cache::TranslationCache;
 use crate::adapters::clients::funtranslations::FunsTranslationsApiClient;
 use crate::application::app_error::AppResult;
+use crate::application::request_id::propagate;
 use crate::application::traits::TranslationRepository;
+use crate::domain::value_objects::{translation::Translation, translator::Translator};
 
+/// Translation repository with stale-while-revalidate caching.
+///
+/// Cached translations older than `soft_ttl` are still served, but trigger a
+/// background refresh (at most one per key at a time). Entries are dropped by
+/// the cache itself once they reach its hard TTL.
 pub struct TranslationRepositoryImpl {
     fun_translations_client: Arc<FunsTranslationsApiClient>,
-    cache: TranslationCache,
+    cache: Arc<dyn TranslationCache>,
+    soft_ttl: Duration,
+    refreshing: Arc<Mutex<HashSet<String>>>,
 }
 
 impl TranslationRepositoryImpl {
-    pub fn new(fun_translations_client: Arc<FunsTranslationsApiClient>) -> Self {
-        let cache = TranslationCache::new(300);
-
+    pub fn new(
+        fun_translations_client: Arc<FunsTranslationsApiClient>,
+        cache: Arc<dyn TranslationCache>,
+        soft_ttl: Duration,
+    ) -> Self {
         Self {
             fun_translations_client,
             cache,
+            soft_ttl,
+            refreshing: Arc::new(Mutex::new(HashSet::new())),
         }
     }
-}
 
-#[async_trait]
-impl TranslationRepository for TranslationRepositoryImpl {
-    async fn get_shakespeare(&self, description: &str) -> AppResult<String> {
-        let cache_key = format!("shakespeare_{}", description);
+    async fn translate_cached(
+        &self,
+        translator: Translator,
+        description: &str,
+    ) -> AppResult<Translation> {
+        let cache_key = format!("{}_{}", translator, description);
+
+        let lookup = self
+            .cache
+            .get_or_try_insert_with(
+                cache_key.clone(),
+                Box::pin(
+                    self.fun_translations_client
+                        .translate(translator, description),
+                ),
+            )
+            .await?;
 
-        if let Some(cached) = self.cache.get(&cache_key).await {
-            tracing::info!("Shakespeare translation cache hit");
-            return Ok(cached);
+        if lookup.from_cache && lookup.value.age() >= self.soft_ttl {
+            tracing::info!(
+                translation_style = %translator,
+                cache = "translations",
+                cache_status = "stale",
+                "Translation cache hit (stale)"
+            );
+            self.spawn_refresh(translator, cache_key, description.to_string());
         }
 
-        tracing::info!("Shakespeare translation cache miss");
+        Ok(Translation {
+            text: lookup.value.translation,
+            from_cache: lookup.from_cache,
+        })
+    }
 
-        let translation = self
-            .fun_translations_client
-            .shakespeare(description)
-            .await?;
+    fn spawn_refresh(&self, translator: Translator, cache_key: String, description: String) {
+        if !self.refreshing.lock().unwrap().insert(cache_key.clone()) {
+            return;
+        }
+
+        let client = self.fun_translations_client.clone();
+        let cache = self.cache.clone();
+        let guard = RefreshGuard {
+            refreshing: self.refreshing.clone(),
+            cache_key: cache_key.clone(),
+        };
 
-        self.cache.insert(cache_key, translation.clone()).await;
+        tokio::spawn(propagate(async move {
+            let _guard = guard;
+            match client.translate(translator, &description).await {
+                Ok(translation) => {
+                    tracing::info!(
+                        translation_style = %translator,
+                        "Translation refreshed"
+                    );
+                    cache.insert(cache_key, translation).await;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        translation_style = %translator,
+                        error = %e,
+                        "Failed to refresh translation"
+                    );
+                }
+            }
+        }));
+    }
+}
 
-        Ok(translation)
+/// Releases a key claimed in `refreshing` however its refresh ends, including
+/// by panic or cancellation.
+struct RefreshGuard {
+    refreshing: Arc<Mutex<HashSet<String>>>,
+    cache_key: String,
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        self.refreshing.lock().unwrap().remove(&self.cache_key);
     }
+}
 
-    async fn get_yoda(&self, description: &str) -> AppResult<String> {
-        let cache_key = format!("yoda_{}", description);
+#[async_trait]
+impl TranslationRepository for TranslationRepositoryImpl {
+    async fn translate(&self, translator: Translator, description: &str) -> AppResult<Translation> {
+        self.translate_cached(translator, description).await
+    }
+}
 
-        if let Some(cached) = self.cache.get(&cache_key).await {
-            tracing::info!("Yoda translation cache hit");
-            return Ok(cached);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        tracing::info!("Yoda translation cache miss");
+    #[tokio::test]
+    async fn test_refresh_guard_releases_key_when_the_task_panics() {
+        let refreshing = Arc::new(Mutex::new(HashSet::from(["yoda_text".to_string()])));
+        let guard = RefreshGuard {
+            refreshing: refreshing.clone(),
+            cache_key: "yoda_text".to_string(),
+        };
 
-        let translation = self.fun_translations_client.yoda(description).await?;
-        self.cache.insert(cache_key, translation.clone()).await;
+        let task = tokio::spawn(async move {
+            let _guard = guard;
+            panic!("translation client panicked");
+        });
 
-        Ok(translation)
+        assert!(task.await.is_err());
+        assert!(refreshing.lock().unwrap().is_empty());
     }
 }
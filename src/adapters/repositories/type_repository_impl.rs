@@ -0,0 +1,69 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::{
+        cache::typed_cache::TypedCache,
+        clients::{dto::pokemon_species_dto::NamedApiResource, pokeapi::PokeApiClient},
+    },
+    application::{app_error::AppResult, concurrency::map_bounded, traits::TypeRepository},
+    domain::services::type_chart::{DamageRelations, TYPES, TypeChart},
+};
+
+const TYPE_FETCH_CONCURRENCY: usize = 6;
+
+/// The chart only changes with a new generation, so it is built once from
+/// every type's damage relations and cached as a whole.
+pub struct TypeRepositoryImpl {
+    pokeapi_client: Arc<PokeApiClient>,
+    cache: TypedCache<(), TypeChart>,
+}
+
+impl TypeRepositoryImpl {
+    pub fn new(pokeapi_client: Arc<PokeApiClient>, cache: TypedCache<(), TypeChart>) -> Self {
+        Self {
+            pokeapi_client,
+            cache,
+        }
+    }
+
+    async fn fetch(&self) -> AppResult<TypeChart> {
+        let client = self.pokeapi_client.clone();
+        let types = map_bounded(TYPES.to_vec(), TYPE_FETCH_CONCURRENCY, move |name| {
+            let client = client.clone();
+            async move { client.pokemon_type(name).await }
+        })
+        .await;
+
+        let names = |types: &[NamedApiResource]| types.iter().map(|t| t.name.clone()).collect();
+        let mut relations = HashMap::new();
+        for pokemon_type in types {
+            let pokemon_type = pokemon_type?;
+            let damage = &pokemon_type.damage_relations;
+            relations.insert(
+                pokemon_type.name,
+                DamageRelations {
+                    double_damage_to: names(&damage.double_damage_to),
+                    half_damage_to: names(&damage.half_damage_to),
+                    no_damage_to: names(&damage.no_damage_to),
+                },
+            );
+        }
+
+        Ok(TypeChart::new(relations))
+    }
+}
+
+#[async_trait]
+impl TypeRepository for TypeRepositoryImpl {
+    async fn get_type_chart(&self) -> AppResult<TypeChart> {
+        let lookup = self
+            .cache
+            .get_or_try_insert_with((), self.fetch())
+            .await
+            .map_err(|e| (*e).clone())?;
+
+        Ok(lookup.value)
+    }
+}
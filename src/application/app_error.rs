@@ -5,11 +5,24 @@ pub enum AppError {
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    /// A `NotFound` for a misspelled name, with the names it may have meant.
+    #[error("Resource not found: {message}")]
+    NotFoundWithSuggestions {
+        message: String,
+        suggestions: Vec<String>,
+    },
+
     #[error("Generic error: {0}")]
     GenericError(String),
 
     #[error("Bad request: {0}")]
     BadRequest(String),
+
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
 }
 
 pub type AppResult<T> = Result<T, AppError>;
@@ -1,3 +1,6 @@
 pub mod app_error;
+pub mod concurrency;
+pub mod request_id;
 pub mod traits;
+pub mod translation_policy;
 pub mod use_cases;
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::future::Future;
+
+use tracing::{Instrument, Span};
+use uuid::Uuid;
+
+/// Longest client-supplied id that is accepted as is.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+/// Id correlating an incoming request with its logs, its error body and the
+/// upstream calls made while serving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    /// Accepts a client-supplied id if it is short, printable ASCII, so it is
+    /// safe to echo back in headers and logs.
+    pub fn parse(value: &str) -> Option<Self> {
+        let valid = !value.is_empty()
+            && value.len() <= MAX_REQUEST_ID_LENGTH
+            && value.bytes().all(|b| b.is_ascii_graphic());
+        valid.then(|| Self(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The id of the request being served by the current task, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    /// Runs `future` with `self` as the current request id.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Carries the current request id, if any, and tracing span into a future
+/// that will run on another task.
+pub fn propagate<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let id = RequestId::current();
+    let future = future.instrument(Span::current());
+    async move {
+        match id {
+            Some(id) => id.scope(future).await,
+            None => future.await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_printable_ids_only() {
+        assert_eq!(
+            RequestId::parse("req-42").map(|id| id.to_string()),
+            Some("req-42".to_string())
+        );
+        assert!(RequestId::parse("").is_none());
+        assert!(RequestId::parse("two words").is_none());
+        assert!(RequestId::parse("line\nbreak").is_none());
+        assert!(RequestId::parse(&"a".repeat(129)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_current_is_scoped_to_the_request() {
+        assert!(RequestId::current().is_none());
+
+        let id = RequestId::parse("req-42").unwrap();
+        let current = id.clone().scope(async { RequestId::current() }).await;
+        assert_eq!(current, Some(id.clone()));
+
+        let spawned = id
+            .clone()
+            .scope(async {
+                let lost = tokio::spawn(async { RequestId::current() });
+                let kept = tokio::spawn(propagate(async { RequestId::current() }));
+                (lost.await.unwrap(), kept.await.unwrap())
+            })
+            .await;
+        assert_eq!(spawned, (None, Some(id)));
+    }
+}
@@ -1,15 +1,105 @@
-use crate::{application::app_error::AppResult, domain::entities::pokemon::Pokemon};
+use std::sync::Arc;
+
+use crate::{
+    application::app_error::AppResult,
+    domain::{
+        entities::{
+            ability_details::AbilityDetails, evolution_chain::EvolutionChain,
+            move_details::MoveDetails, pokemon::Pokemon, species_index::SpeciesIndex,
+        },
+        services::type_chart::TypeChart,
+        value_objects::{
+            species_filter::SpeciesFilter, species_rarity::SpeciesRarity,
+            species_summary::SpeciesSummary, translation::Translation, translator::Translator,
+        },
+    },
+};
 use async_trait::async_trait;
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait PokemonRepository: Send + Sync {
+    /// `name` is a species name, a national dex number or the name of one of
+    /// a species' varieties, which resolves to that species.
     async fn get_pokemon<'a>(&'a self, name: &'a str) -> AppResult<Pokemon>;
+
+    /// Like `get_pokemon`, additionally populating types, base stats,
+    /// abilities, size and sprites. Costs an extra upstream call.
+    async fn get_pokemon_details<'a>(&'a self, name: &'a str) -> AppResult<Pokemon>;
+
+    /// The species listed under `number` in a regional Pokedex, e.g. `kanto`.
+    async fn get_pokemon_by_pokedex_number<'a>(
+        &'a self,
+        pokedex: &'a str,
+        number: u32,
+    ) -> AppResult<Pokemon>;
+
+    /// Every species with the attributes PokéAPI can list in bulk, ordered by
+    /// national dex number.
+    async fn get_species_catalog(&self) -> AppResult<Vec<SpeciesSummary>>;
+
+    /// Fetches the species catalog again, replacing any cached one.
+    async fn refresh_species_catalog(&self) -> AppResult<()> {
+        self.get_species_catalog().await.map(|_| ())
+    }
+
+    /// The species from `get_species_catalog` matching `filter`'s generation,
+    /// habitat and color. Legendary and mythical criteria are not checked.
+    async fn list_species<'a>(
+        &'a self,
+        filter: &'a SpeciesFilter,
+    ) -> AppResult<Vec<SpeciesSummary>> {
+        Ok(self
+            .get_species_catalog()
+            .await?
+            .into_iter()
+            .filter(|species| filter.matches_summary(species))
+            .collect())
+    }
+
+    /// Whether the species `name` is legendary or mythical.
+    async fn get_species_rarity<'a>(&'a self, name: &'a str) -> AppResult<SpeciesRarity> {
+        Ok(self.get_pokemon(name).await?.rarity())
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait TranslationRepository: Send + Sync {
-    async fn get_shakespeare(&self, description: &str) -> AppResult<String>;
-    async fn get_yoda(&self, description: &str) -> AppResult<String>;
+    async fn translate(&self, translator: Translator, description: &str) -> AppResult<Translation>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait EvolutionRepository: Send + Sync {
+    async fn get_evolution_chain(&self, id: u32) -> AppResult<EvolutionChain>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TypeRepository: Send + Sync {
+    async fn get_type_chart(&self) -> AppResult<TypeChart>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AbilityRepository: Send + Sync {
+    async fn get_ability<'a>(&'a self, name: &'a str) -> AppResult<AbilityDetails>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MoveRepository: Send + Sync {
+    async fn get_move<'a>(&'a self, name: &'a str) -> AppResult<MoveDetails>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait SpeciesIndexRepository: Send + Sync {
+    /// Every species with its localized names, or `None` until the index has
+    /// been built. Never builds it itself, so callers don't wait on it.
+    async fn get_species_index(&self) -> Option<Arc<SpeciesIndex>>;
+
+    /// Builds the index again, replacing the current one on success.
+    async fn refresh_species_index(&self) -> AppResult<()>;
 }
@@ -0,0 +1,191 @@
+use crate::domain::{
+    entities::pokemon::Pokemon,
+    value_objects::{generation::Generation, translator::Translator},
+};
+
+pub trait TranslationPolicy: Send + Sync {
+    fn select(&self, pokemon: &Pokemon) -> Translator;
+
+    /// Whether `select` looks at data only present on detailed lookups
+    /// (currently types).
+    fn needs_details(&self) -> bool {
+        false
+    }
+}
+
+/// Conditions a Pokemon must meet for a rule to apply. Unset conditions match
+/// anything; a rule with no conditions matches every Pokemon.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleConditions {
+    pub habitat: Option<String>,
+    pub is_legendary: Option<bool>,
+    pub is_mythical: Option<bool>,
+    pub generation: Option<Generation>,
+    pub pokemon_type: Option<String>,
+}
+
+impl RuleConditions {
+    fn matches(&self, pokemon: &Pokemon) -> bool {
+        self.habitat
+            .as_deref()
+            .is_none_or(|habitat| pokemon.has_habitat(habitat))
+            && self
+                .is_legendary
+                .is_none_or(|is_legendary| pokemon.is_legendary() == is_legendary)
+            && self
+                .is_mythical
+                .is_none_or(|is_mythical| pokemon.is_mythical() == is_mythical)
+            && self
+                .generation
+                .is_none_or(|generation| pokemon.generation() == Some(generation))
+            && self.pokemon_type.as_deref().is_none_or(|pokemon_type| {
+                pokemon
+                    .types()
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(pokemon_type))
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationRule {
+    pub conditions: RuleConditions,
+    pub translator: Translator,
+}
+
+/// Picks the translator of the first rule whose conditions match, falling back
+/// to `default_translator` when none does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleBasedTranslationPolicy {
+    rules: Vec<TranslationRule>,
+    default_translator: Translator,
+}
+
+impl RuleBasedTranslationPolicy {
+    pub fn new(rules: Vec<TranslationRule>, default_translator: Translator) -> Self {
+        Self {
+            rules,
+            default_translator,
+        }
+    }
+}
+
+impl Default for RuleBasedTranslationPolicy {
+    /// Yoda for cave or legendary Pokemon, Shakespeare otherwise.
+    fn default() -> Self {
+        Self::new(
+            vec![
+                TranslationRule {
+                    conditions: RuleConditions {
+                        habitat: Some("cave".to_string()),
+                        ..RuleConditions::default()
+                    },
+                    translator: Translator::Yoda,
+                },
+                TranslationRule {
+                    conditions: RuleConditions {
+                        is_legendary: Some(true),
+                        ..RuleConditions::default()
+                    },
+                    translator: Translator::Yoda,
+                },
+            ],
+            Translator::Shakespeare,
+        )
+    }
+}
+
+impl TranslationPolicy for RuleBasedTranslationPolicy {
+    fn select(&self, pokemon: &Pokemon) -> Translator {
+        self.rules
+            .iter()
+            .find(|rule| rule.conditions.matches(pokemon))
+            .map(|rule| rule.translator)
+            .unwrap_or(self.default_translator)
+    }
+
+    fn needs_details(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.conditions.pokemon_type.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pokemon(habitat: &str, is_legendary: bool) -> Pokemon {
+        Pokemon::new(
+            "test".to_string(),
+            "Test pokemon".to_string(),
+            habitat.to_string(),
+            is_legendary,
+        )
+    }
+
+    #[test]
+    fn test_default_policy_matches_original_rules() {
+        let policy = RuleBasedTranslationPolicy::default();
+
+        assert_eq!(policy.select(&pokemon("Cave", false)), Translator::Yoda);
+        assert_eq!(policy.select(&pokemon("Rare", true)), Translator::Yoda);
+        assert_eq!(
+            policy.select(&pokemon("Forest", false)),
+            Translator::Shakespeare
+        );
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let policy = RuleBasedTranslationPolicy::new(
+            vec![
+                TranslationRule {
+                    conditions: RuleConditions {
+                        is_mythical: Some(true),
+                        ..RuleConditions::default()
+                    },
+                    translator: Translator::Shakespeare,
+                },
+                TranslationRule {
+                    conditions: RuleConditions::default(),
+                    translator: Translator::Yoda,
+                },
+            ],
+            Translator::Shakespeare,
+        );
+
+        assert_eq!(
+            policy.select(&pokemon("Forest", false).with_mythical(true)),
+            Translator::Shakespeare
+        );
+        assert_eq!(policy.select(&pokemon("Forest", false)), Translator::Yoda);
+    }
+
+    #[test]
+    fn test_all_conditions_of_a_rule_must_match() {
+        let policy = RuleBasedTranslationPolicy::new(
+            vec![TranslationRule {
+                conditions: RuleConditions {
+                    generation: Generation::new(1),
+                    pokemon_type: Some("ghost".to_string()),
+                    ..RuleConditions::default()
+                },
+                translator: Translator::Yoda,
+            }],
+            Translator::Shakespeare,
+        );
+
+        let gengar = pokemon("Cave", false)
+            .with_generation(Generation::new(1).unwrap())
+            .with_types(vec!["ghost".to_string(), "poison".to_string()]);
+        let misdreavus = pokemon("Cave", false)
+            .with_generation(Generation::new(2).unwrap())
+            .with_types(vec!["ghost".to_string()]);
+
+        assert_eq!(policy.select(&gengar), Translator::Yoda);
+        assert_eq!(policy.select(&misdreavus), Translator::Shakespeare);
+        assert!(policy.needs_details());
+        assert!(!RuleBasedTranslationPolicy::default().needs_details());
+    }
+}
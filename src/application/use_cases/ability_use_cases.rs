@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{app_error::AppResult, traits::AbilityRepository},
+    domain::entities::ability_details::AbilityDetails,
+};
+
+#[derive(Clone)]
+pub struct AbilityUseCases {
+    ability_repository: Arc<dyn AbilityRepository>,
+}
+
+impl AbilityUseCases {
+    pub fn new(ability_repository: Arc<dyn AbilityRepository>) -> Self {
+        Self { ability_repository }
+    }
+
+    pub async fn get_ability(&self, name: &str) -> AppResult<AbilityDetails> {
+        self.ability_repository.get_ability(name).await
+    }
+}
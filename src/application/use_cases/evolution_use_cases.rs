@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        app_error::{AppError, AppResult},
+        traits::{EvolutionRepository, PokemonRepository},
+    },
+    domain::entities::evolution_chain::EvolutionChain,
+};
+
+#[derive(Clone)]
+pub struct EvolutionUseCases {
+    pokemon_repository: Arc<dyn PokemonRepository>,
+    evolution_repository: Arc<dyn EvolutionRepository>,
+}
+
+impl EvolutionUseCases {
+    pub fn new(
+        pokemon_repository: Arc<dyn PokemonRepository>,
+        evolution_repository: Arc<dyn EvolutionRepository>,
+    ) -> Self {
+        Self {
+            pokemon_repository,
+            evolution_repository,
+        }
+    }
+
+    /// Returns the whole evolution chain the Pokemon belongs to.
+    pub async fn get_evolution_chain(&self, name: &str) -> AppResult<EvolutionChain> {
+        let pokemon = self.pokemon_repository.get_pokemon(name).await?;
+
+        let chain_id = pokemon.evolution_chain_id().ok_or_else(|| {
+            AppError::NotFound(format!("No evolution chain for '{}'", pokemon.name()))
+        })?;
+
+        self.evolution_repository
+            .get_evolution_chain(chain_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::traits::{MockEvolutionRepository, MockPokemonRepository};
+    use crate::domain::entities::{evolution_chain::EvolutionStage, pokemon::Pokemon};
+    use mockall::predicate::*;
+
+    fn pokemon() -> Pokemon {
+        Pokemon::new(
+            "pichu".to_string(),
+            "Tiny mouse".to_string(),
+            "Forest".to_string(),
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_chain_is_looked_up_by_species_chain_id() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_evolution_repo = MockEvolutionRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(|_| Ok(pokemon().with_evolution_chain_id(10)));
+        mock_evolution_repo
+            .expect_get_evolution_chain()
+            .with(eq(10))
+            .times(1)
+            .returning(|id| {
+                Ok(EvolutionChain::new(
+                    id,
+                    EvolutionStage::new("pichu".to_string(), true, vec![], vec![]),
+                ))
+            });
+
+        let use_case =
+            EvolutionUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_evolution_repo));
+
+        let chain = use_case.get_evolution_chain("pichu").await.unwrap();
+
+        assert_eq!(chain.id(), 10);
+        assert_eq!(chain.root().species(), "pichu");
+    }
+
+    #[tokio::test]
+    async fn test_species_without_chain_is_not_found() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_evolution_repo = MockEvolutionRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(|_| Ok(pokemon()));
+        mock_evolution_repo.expect_get_evolution_chain().never();
+
+        let use_case =
+            EvolutionUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_evolution_repo));
+
+        assert!(matches!(
+            use_case.get_evolution_chain("pichu").await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
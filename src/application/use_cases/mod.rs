@@ -1 +1,5 @@
+pub mod ability_use_cases;
+pub mod evolution_use_cases;
+pub mod move_use_cases;
 pub mod pokemon_use_cases;
+pub mod type_use_cases;
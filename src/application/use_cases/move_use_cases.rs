@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        app_error::AppResult,
+        traits::{MoveRepository, PokemonRepository},
+    },
+    domain::{
+        entities::{move_details::MoveDetails, pokemon::Pokemon},
+        value_objects::learned_move::{LearnedMove, LearnsetFilter},
+    },
+};
+
+#[derive(Clone)]
+pub struct MoveUseCases {
+    pokemon_repository: Arc<dyn PokemonRepository>,
+    move_repository: Arc<dyn MoveRepository>,
+}
+
+impl MoveUseCases {
+    pub fn new(
+        pokemon_repository: Arc<dyn PokemonRepository>,
+        move_repository: Arc<dyn MoveRepository>,
+    ) -> Self {
+        Self {
+            pokemon_repository,
+            move_repository,
+        }
+    }
+
+    pub async fn get_move(&self, name: &str) -> AppResult<MoveDetails> {
+        self.move_repository.get_move(name).await
+    }
+
+    /// The Pokemon, or the variety `name` refers to, and the moves it learns
+    /// that match `filter`.
+    pub async fn get_learnset(
+        &self,
+        name: &str,
+        filter: &LearnsetFilter,
+    ) -> AppResult<(Pokemon, Vec<LearnedMove>)> {
+        let pokemon = self.pokemon_repository.get_pokemon_details(name).await?;
+
+        let learnset = pokemon.learnset(filter).into_iter().cloned().collect();
+        Ok((pokemon, learnset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::traits::{MockMoveRepository, MockPokemonRepository};
+    use mockall::predicate::*;
+
+    fn learned(name: &str, method: &str, level: Option<u8>, version_group: &str) -> LearnedMove {
+        LearnedMove {
+            name: name.to_string(),
+            method: method.to_string(),
+            level,
+            version_group: version_group.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_learnset_is_filtered_and_ordered_by_level() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon_details()
+            .with(eq("pikachu"))
+            .times(1)
+            .returning(|_| {
+                Ok(Pokemon::new(
+                    "pikachu".to_string(),
+                    "Mouse".to_string(),
+                    "forest".to_string(),
+                    false,
+                )
+                .with_learnset(vec![
+                    learned("thunderbolt", "level-up", Some(26), "red-blue"),
+                    learned("thunderbolt", "machine", None, "red-blue"),
+                    learned("thunder-shock", "level-up", Some(1), "red-blue"),
+                    learned("thunder-shock", "level-up", Some(1), "yellow"),
+                ]))
+            });
+
+        let use_case = MoveUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockMoveRepository::new()),
+        );
+        let filter = LearnsetFilter {
+            version_group: Some("red-blue".to_string()),
+            method: Some("level-up".to_string()),
+        };
+
+        let (_, learnset) = use_case.get_learnset("pikachu", &filter).await.unwrap();
+
+        let names: Vec<_> = learnset.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["thunder-shock", "thunderbolt"]);
+    }
+}
@@ -2,56 +2,308 @@ use std::sync::Arc;
 
 use crate::{
     application::{
-        app_error::AppResult,
-        traits::{PokemonRepository, TranslationRepository},
+        app_error::{AppError, AppResult},
+        concurrency::map_bounded,
+        traits::{PokemonRepository, SpeciesIndexRepository, TranslationRepository},
+        translation_policy::TranslationPolicy,
+    },
+    domain::{
+        entities::{pokemon::Pokemon, species_index::SearchMatch},
+        value_objects::{
+            language::LanguagePreference, page::Page, pokemon_lookup::PokemonLookup,
+            species_filter::SpeciesFilter, species_summary::SpeciesSummary,
+            translation::FallbackReason, translator::Translator,
+        },
     },
-    domain::entities::pokemon::Pokemon,
 };
 
 #[derive(Clone)]
 pub struct PokemonUseCases {
     pokemon_repository: Arc<dyn PokemonRepository>,
     translation_repository: Arc<dyn TranslationRepository>,
+    translation_policy: Arc<dyn TranslationPolicy>,
+    species_index: Option<Arc<dyn SpeciesIndexRepository>>,
+    batch_concurrency: usize,
 }
 
+/// How many "did you mean" suggestions a `NotFound` carries at most.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Species looked up at once when filtering on legendary or mythical status.
+const RARITY_LOOKUP_CONCURRENCY: usize = 8;
+
+const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
 impl PokemonUseCases {
     pub fn new(
         pokemon_repository: Arc<dyn PokemonRepository>,
         translation_repository: Arc<dyn TranslationRepository>,
+        translation_policy: Arc<dyn TranslationPolicy>,
     ) -> Self {
         Self {
             pokemon_repository,
             translation_repository,
+            translation_policy,
+            species_index: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
         }
     }
 
-    pub async fn get_pokemon(&self, name: &str) -> AppResult<Pokemon> {
-        self.pokemon_repository.get_pokemon(name).await
+    /// How many Pokemon of a batch are looked up at once.
+    pub fn with_batch_concurrency(mut self, batch_concurrency: usize) -> Self {
+        self.batch_concurrency = batch_concurrency;
+        self
     }
 
-    pub async fn get_pokemon_translated(&self, name: &str) -> AppResult<Pokemon> {
-        let mut pokemon = self.pokemon_repository.get_pokemon(name).await?;
+    /// Enables name search and suggestions for misspelled names.
+    pub fn with_species_index(mut self, species_index: Arc<dyn SpeciesIndexRepository>) -> Self {
+        self.species_index = Some(species_index);
+        self
+    }
 
-        let translation = if pokemon.is_cave() || pokemon.is_legendary() {
-            tracing::debug!("Using Yoda translation");
-            self.translation_repository
-                .get_yoda(pokemon.description())
-                .await
-        } else {
-            tracing::debug!("Using Shakespeare translation");
-            self.translation_repository
-                .get_shakespeare(pokemon.description())
-                .await
+    /// Looks every name up, translated when `translated` is set. Each name
+    /// gets its own result, in input order, so one failure does not fail the
+    /// others.
+    pub async fn get_pokemon_batch(
+        &self,
+        names: Vec<String>,
+        translated: bool,
+    ) -> Vec<AppResult<Pokemon>> {
+        let use_cases = self.clone();
+        map_bounded(names, self.batch_concurrency, move |name| {
+            let use_cases = use_cases.clone();
+            async move {
+                let lookup = PokemonLookup::parse(&name, None).map_err(AppError::BadRequest)?;
+                if translated {
+                    let details = use_cases.translation_policy.needs_details();
+                    let pokemon = use_cases.find(&lookup, details).await?;
+                    Ok(use_cases.translate(pokemon, None).await)
+                } else {
+                    use_cases
+                        .get_pokemon(&lookup, &LanguagePreference::default(), None)
+                        .await
+                }
+            }
+        })
+        .await
+    }
+
+    /// The species matching `filter`, ordered by national dex number.
+    pub async fn list_pokemon(
+        &self,
+        filter: &SpeciesFilter,
+        offset: usize,
+        limit: usize,
+    ) -> AppResult<Page<SpeciesSummary>> {
+        let mut species = self.pokemon_repository.list_species(filter).await?;
+        if filter.needs_rarity() {
+            species = self.filter_by_rarity(species, filter).await;
+        }
+
+        Ok(Page {
+            total: species.len(),
+            items: species.into_iter().skip(offset).take(limit).collect(),
+        })
+    }
+
+    /// Keeps the species whose rarity matches `filter`. Species whose rarity
+    /// can't be looked up are left out rather than failing the whole list.
+    async fn filter_by_rarity(
+        &self,
+        species: Vec<SpeciesSummary>,
+        filter: &SpeciesFilter,
+    ) -> Vec<SpeciesSummary> {
+        let repository = self.pokemon_repository.clone();
+        let lookups = map_bounded(species, RARITY_LOOKUP_CONCURRENCY, move |species| {
+            let repository = repository.clone();
+            async move {
+                let rarity = repository.get_species_rarity(&species.name).await;
+                (species, rarity)
+            }
+        })
+        .await;
+
+        lookups
+            .into_iter()
+            .filter_map(|(species, rarity)| match rarity {
+                Ok(rarity) => filter.matches_rarity(rarity).then_some(species),
+                Err(e) => {
+                    tracing::warn!(
+                        pokemon = species.name,
+                        error = %e,
+                        "Skipping species whose rarity failed to load"
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Refreshes the species catalog that listing reads.
+    pub async fn refresh_species_catalog(&self) -> AppResult<()> {
+        self.pokemon_repository.refresh_species_catalog().await
+    }
+
+    /// Refreshes the species index that search and suggestions read.
+    pub async fn refresh_species_index(&self) -> AppResult<()> {
+        match &self.species_index {
+            Some(species_index) => species_index.refresh_species_index().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Species whose name, in any known language, starts with or is close to
+    /// `query`. Unavailable until the species index has been built.
+    pub async fn search(&self, query: &str, limit: usize) -> AppResult<Vec<SearchMatch>> {
+        let Some(species_index) = &self.species_index else {
+            return Ok(Vec::new());
+        };
+        match species_index.get_species_index().await {
+            Some(index) => Ok(index.search(query, limit)),
+            None => Err(AppError::ServiceUnavailable(
+                "The species index is still being built".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the Pokemon with its description in the preferred language,
+    /// taken from the given game `version` when set.
+    pub async fn get_pokemon(
+        &self,
+        lookup: &PokemonLookup,
+        language: &LanguagePreference,
+        version: Option<&str>,
+    ) -> AppResult<Pokemon> {
+        let pokemon = self.find(lookup, false).await?;
+        localize(pokemon, language, version)
+    }
+
+    pub async fn get_pokemon_details(
+        &self,
+        lookup: &PokemonLookup,
+        language: &LanguagePreference,
+        version: Option<&str>,
+    ) -> AppResult<Pokemon> {
+        let pokemon = self.find(lookup, true).await?;
+        localize(pokemon, language, version)
+    }
+
+    async fn find(&self, lookup: &PokemonLookup, details: bool) -> AppResult<Pokemon> {
+        let name = match lookup {
+            PokemonLookup::Name(name) => name.to_string(),
+            PokemonLookup::NationalDex(number) => number.to_string(),
+            PokemonLookup::RegionalDex { pokedex, number } => {
+                let pokemon = self
+                    .pokemon_repository
+                    .get_pokemon_by_pokedex_number(pokedex.as_str(), *number)
+                    .await?;
+                if !details {
+                    return Ok(pokemon);
+                }
+                pokemon.name().to_string()
+            }
         };
 
-        if let Ok(translation) = translation {
-            pokemon.set_translated_description(translation);
+        self.lookup(&name, details).await
+    }
+
+    /// Looks `name` up, suggesting close species names when it does not
+    /// exist and the species index has been built.
+    async fn lookup(&self, name: &str, details: bool) -> AppResult<Pokemon> {
+        let result = if details {
+            self.pokemon_repository.get_pokemon_details(name).await
         } else {
-            tracing::warn!("Failed to translate pokemon description using standard translation");
-            pokemon.set_translated_description(pokemon.description().to_string());
+            self.pokemon_repository.get_pokemon(name).await
+        };
+
+        let Some(species_index) = &self.species_index else {
+            return result;
+        };
+        match result {
+            Err(AppError::NotFound(message)) if name.parse::<u32>().is_err() => {
+                let suggestions = match species_index.get_species_index().await {
+                    Some(index) => index.suggest(name, MAX_SUGGESTIONS),
+                    None => Vec::new(),
+                };
+                if suggestions.is_empty() {
+                    Err(AppError::NotFound(message))
+                } else {
+                    Err(AppError::NotFoundWithSuggestions {
+                        message,
+                        suggestions,
+                    })
+                }
+            }
+            result => result,
         }
+    }
+
+    /// Translates the Pokemon's description with `translator` when given,
+    /// otherwise with the one chosen by the translation policy.
+    pub async fn get_pokemon_translated(
+        &self,
+        name: &str,
+        translator: Option<Translator>,
+    ) -> AppResult<Pokemon> {
+        let details = translator.is_none() && self.translation_policy.needs_details();
+        let pokemon = self.lookup(name, details).await?;
+        Ok(self.translate(pokemon, translator).await)
+    }
+
+    /// Applies the translation to `pokemon`, keeping its original description
+    /// when the translation fails.
+    async fn translate(&self, mut pokemon: Pokemon, translator: Option<Translator>) -> Pokemon {
+        let translator = translator.unwrap_or_else(|| self.translation_policy.select(&pokemon));
+        tracing::debug!(
+            pokemon = pokemon.name(),
+            translation_style = %translator,
+            "Using translation"
+        );
+
+        let translation = self
+            .translation_repository
+            .translate(translator, pokemon.description())
+            .await;
+
+        let outcome = translation.map_err(|e| {
+            tracing::warn!(
+                pokemon = pokemon.name(),
+                translation_style = %translator,
+                error = %e,
+                "Failed to translate pokemon description"
+            );
+            fallback_reason(&e)
+        });
+        pokemon.apply_translation(translator, outcome);
+
+        pokemon
+    }
+}
+
+fn localize(
+    mut pokemon: Pokemon,
+    language: &LanguagePreference,
+    version: Option<&str>,
+) -> AppResult<Pokemon> {
+    if let Some(version) = version
+        && !pokemon.has_flavor_text_for(version)
+    {
+        return Err(AppError::NotFound(format!(
+            "No flavor text for '{}' in version '{}'",
+            pokemon.name(),
+            version
+        )));
+    }
+
+    pokemon.localize(language, version);
+    Ok(pokemon)
+}
 
-        Ok(pokemon)
+fn fallback_reason(error: &AppError) -> FallbackReason {
+    match error {
+        AppError::RateLimited(_) => FallbackReason::RateLimited,
+        AppError::ServiceUnavailable(_) => FallbackReason::ServiceUnavailable,
+        _ => FallbackReason::TranslationFailed,
     }
 }
 
@@ -59,11 +311,22 @@ impl PokemonUseCases {
 mod tests {
     use super::*;
     use crate::application::{
-        app_error::AppError,
-        traits::{MockPokemonRepository, MockTranslationRepository},
+        traits::{MockPokemonRepository, MockSpeciesIndexRepository, MockTranslationRepository},
+        translation_policy::{RuleBasedTranslationPolicy, RuleConditions, TranslationRule},
+    };
+    use crate::domain::{
+        entities::species_index::{IndexedSpecies, SpeciesIndex},
+        value_objects::{species_rarity::SpeciesRarity, translation::Translation},
     };
     use mockall::predicate::*;
 
+    fn translated(text: &str) -> AppResult<Translation> {
+        Ok(Translation {
+            text: text.to_string(),
+            from_cache: false,
+        })
+    }
+
     #[tokio::test]
     async fn test_get_pokemon_calls_repository() {
         let mut mock_pokemon_repo = MockPokemonRepository::new();
@@ -82,15 +345,264 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(expected_pokemon.clone()));
 
-        let use_case =
-            PokemonUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_translation_repo));
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
 
-        let result = use_case.get_pokemon("bulbasaur").await;
+        let result = use_case
+            .get_pokemon(
+                &PokemonLookup::Name("bulbasaur".parse().unwrap()),
+                &LanguagePreference::default(),
+                None,
+            )
+            .await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap().name(), "Bulbasaur");
     }
 
+    #[tokio::test]
+    async fn test_misspelled_name_gets_suggestions() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_species_index = MockSpeciesIndexRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(|name| Err(AppError::NotFound(name.to_string())));
+        mock_species_index.expect_get_species_index().returning(|| {
+            Some(Arc::new(SpeciesIndex::new(vec![IndexedSpecies {
+                name: "pikachu".to_string(),
+                names: vec![],
+            }])))
+        });
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockTranslationRepository::new()),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        )
+        .with_species_index(Arc::new(mock_species_index));
+
+        let result = use_case
+            .get_pokemon(
+                &PokemonLookup::Name("pikchu".parse().unwrap()),
+                &LanguagePreference::default(),
+                None,
+            )
+            .await;
+
+        match result {
+            Err(AppError::NotFoundWithSuggestions { suggestions, .. }) => {
+                assert_eq!(suggestions, ["pikachu"])
+            }
+            other => panic!("expected suggestions, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cold_species_index_gives_plain_not_found() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_species_index = MockSpeciesIndexRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(|name| Err(AppError::NotFound(name.to_string())));
+        mock_species_index
+            .expect_get_species_index()
+            .returning(|| None);
+        mock_species_index.expect_refresh_species_index().never();
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockTranslationRepository::new()),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        )
+        .with_species_index(Arc::new(mock_species_index));
+
+        let result = use_case
+            .get_pokemon(
+                &PokemonLookup::Name("pikchu".parse().unwrap()),
+                &LanguagePreference::default(),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+
+        assert!(matches!(
+            use_case.search("pika", 5).await,
+            Err(AppError::ServiceUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_keeps_order_and_reports_failures_per_item() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(|name| match name {
+                "missingno" => Err(AppError::NotFound(name.to_string())),
+                _ => Ok(Pokemon::new(
+                    name.to_string(),
+                    "desc".to_string(),
+                    "forest".to_string(),
+                    false,
+                )),
+            });
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockTranslationRepository::new()),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        )
+        .with_batch_concurrency(2);
+
+        let names = ["pikachu", "missingno", "pika/chu", "raichu"];
+        let results = use_case
+            .get_pokemon_batch(names.iter().map(|n| n.to_string()).collect(), false)
+            .await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().name(), "pikachu");
+        assert!(matches!(results[1], Err(AppError::NotFound(_))));
+        assert!(matches!(results[2], Err(AppError::BadRequest(_))));
+        assert_eq!(results[3].as_ref().unwrap().name(), "raichu");
+    }
+
+    #[tokio::test]
+    async fn test_translated_batch_takes_the_same_items_as_untranslated() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_translation_repo = MockTranslationRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .with(eq("25"))
+            .times(2)
+            .returning(|_| {
+                Ok(Pokemon::new(
+                    "pikachu".to_string(),
+                    "desc".to_string(),
+                    "forest".to_string(),
+                    false,
+                ))
+            });
+        mock_translation_repo
+            .expect_translate()
+            .with(eq(Translator::Shakespeare), eq("desc"))
+            .times(1)
+            .returning(|_, _| translated("shakespeare desc"));
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
+
+        for is_translated in [false, true] {
+            let results = use_case
+                .get_pokemon_batch(vec!["25".to_string()], is_translated)
+                .await;
+            assert_eq!(results[0].as_ref().unwrap().name(), "pikachu");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_pokemon_filters_legendaries_then_pages() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+
+        mock_pokemon_repo.expect_list_species().returning(|_| {
+            Ok(["articuno", "dratini", "zapdos", "moltres", "missingno"]
+                .iter()
+                .enumerate()
+                .map(|(id, name)| SpeciesSummary {
+                    id: id as u32 + 144,
+                    name: name.to_string(),
+                    generation: None,
+                    habitat: None,
+                    color: None,
+                })
+                .collect())
+        });
+        mock_pokemon_repo
+            .expect_get_species_rarity()
+            .returning(|name| match name {
+                "missingno" => Err(AppError::GenericError("upstream down".to_string())),
+                _ => Ok(SpeciesRarity {
+                    is_legendary: name != "dratini",
+                    is_mythical: false,
+                }),
+            });
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockTranslationRepository::new()),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
+        let filter = SpeciesFilter {
+            is_legendary: Some(true),
+            ..SpeciesFilter::default()
+        };
+
+        let page = use_case.list_pokemon(&filter, 1, 5).await.unwrap();
+
+        assert_eq!(page.total, 3);
+        let names: Vec<_> = page.items.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["zapdos", "moltres"]);
+    }
+
+    #[tokio::test]
+    async fn test_regional_dex_lookup_resolves_species_before_details() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon_by_pokedex_number()
+            .with(eq("kanto"), eq(25))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Pokemon::new(
+                    "pikachu".to_string(),
+                    "Electric mouse".to_string(),
+                    "forest".to_string(),
+                    false,
+                ))
+            });
+        mock_pokemon_repo
+            .expect_get_pokemon_details()
+            .with(eq("pikachu"))
+            .times(1)
+            .returning(|_| {
+                Ok(Pokemon::new(
+                    "pikachu".to_string(),
+                    "Electric mouse".to_string(),
+                    "forest".to_string(),
+                    false,
+                )
+                .with_types(vec!["electric".to_string()]))
+            });
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(MockTranslationRepository::new()),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
+
+        let pokemon = use_case
+            .get_pokemon_details(
+                &PokemonLookup::RegionalDex {
+                    pokedex: "kanto".parse().unwrap(),
+                    number: 25,
+                },
+                &LanguagePreference::default(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(pokemon.types(), ["electric".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_legendary_pokemon_gets_yoda_translation() {
         let mut mock_pokemon_repo = MockPokemonRepository::new();
@@ -108,15 +620,21 @@ mod tests {
             .returning(move |_| Ok(legendary.clone()));
 
         mock_translation_repo
-            .expect_get_yoda()
-            .with(eq("Legendary bird"))
+            .expect_translate()
+            .with(eq(Translator::Yoda), eq("Legendary bird"))
             .times(1)
-            .returning(|_| Ok("Legendary, a bird is".to_string()));
+            .returning(|_, _| translated("Legendary, a bird is"));
 
-        let use_case =
-            PokemonUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_translation_repo));
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
 
-        let result = use_case.get_pokemon_translated("lugia").await.unwrap();
+        let result = use_case
+            .get_pokemon_translated("lugia", None)
+            .await
+            .unwrap();
 
         assert_eq!(result.description(), "Legendary, a bird is");
     }
@@ -138,14 +656,21 @@ mod tests {
             .returning(move |_| Ok(cave_pokemon.clone()));
 
         mock_translation_repo
-            .expect_get_yoda()
+            .expect_translate()
+            .with(eq(Translator::Yoda), always())
             .times(1)
-            .returning(|_| Ok("Small, a bat pokemon is".to_string()));
+            .returning(|_, _| translated("Small, a bat pokemon is"));
 
-        let use_case =
-            PokemonUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_translation_repo));
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
 
-        let result = use_case.get_pokemon_translated("zubat").await.unwrap();
+        let result = use_case
+            .get_pokemon_translated("zubat", None)
+            .await
+            .unwrap();
 
         assert_eq!(result.description(), "Small, a bat pokemon is");
     }
@@ -167,15 +692,21 @@ mod tests {
             .returning(move |_| Ok(regular.clone()));
 
         mock_translation_repo
-            .expect_get_shakespeare()
-            .with(eq("Bird pokemon"))
+            .expect_translate()
+            .with(eq(Translator::Shakespeare), eq("Bird pokemon"))
             .times(1)
-            .returning(|_| Ok("A creature of the winged persuasion".to_string()));
+            .returning(|_, _| translated("A creature of the winged persuasion"));
 
-        let use_case =
-            PokemonUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_translation_repo));
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
 
-        let result = use_case.get_pokemon_translated("pidgeot").await.unwrap();
+        let result = use_case
+            .get_pokemon_translated("pidgeot", None)
+            .await
+            .unwrap();
 
         assert_eq!(result.description(), "A creature of the winged persuasion");
     }
@@ -197,14 +728,144 @@ mod tests {
             .returning(move |_| Ok(pokemon.clone()));
 
         mock_translation_repo
-            .expect_get_shakespeare()
-            .returning(|_| Err(AppError::GenericError("Rate limit".to_string())));
+            .expect_translate()
+            .returning(|_, _| Err(AppError::GenericError("Rate limit".to_string())));
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
+
+        let result = use_case
+            .get_pokemon_translated("pikachu", None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.description(), "Electric mouse");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_translation_reports_fallback_reason() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_translation_repo = MockTranslationRepository::new();
+
+        let pokemon = Pokemon::new(
+            "Pikachu".to_string(),
+            "Electric mouse".to_string(),
+            "forest".to_string(),
+            false,
+        );
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(move |_| Ok(pokemon.clone()));
+
+        mock_translation_repo
+            .expect_translate()
+            .returning(|_, _| Err(AppError::RateLimited("Rate limit".to_string())));
 
-        let use_case =
-            PokemonUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_translation_repo));
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
 
-        let result = use_case.get_pokemon_translated("pikachu").await.unwrap();
+        let result = use_case
+            .get_pokemon_translated("pikachu", None)
+            .await
+            .unwrap();
 
         assert_eq!(result.description(), "Electric mouse");
+        assert_eq!(
+            result.translation().unwrap().fallback_reason,
+            Some(FallbackReason::RateLimited)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explicit_translator_overrides_policy() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_translation_repo = MockTranslationRepository::new();
+
+        let legendary = Pokemon::new(
+            "Lugia".to_string(),
+            "Legendary bird".to_string(),
+            "sea".to_string(),
+            true,
+        );
+
+        mock_pokemon_repo
+            .expect_get_pokemon()
+            .returning(move |_| Ok(legendary.clone()));
+
+        mock_translation_repo
+            .expect_translate()
+            .with(eq(Translator::Pirate), eq("Legendary bird"))
+            .times(1)
+            .returning(|_, _| translated("Legendary bird, arr"));
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(RuleBasedTranslationPolicy::default()),
+        );
+
+        let result = use_case
+            .get_pokemon_translated("lugia", Some(Translator::Pirate))
+            .await
+            .unwrap();
+
+        assert_eq!(result.description(), "Legendary bird, arr");
+    }
+
+    #[tokio::test]
+    async fn test_type_rules_use_detailed_lookup() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_translation_repo = MockTranslationRepository::new();
+
+        let gastly = Pokemon::new(
+            "Gastly".to_string(),
+            "Gas pokemon".to_string(),
+            "cave".to_string(),
+            false,
+        )
+        .with_types(vec!["ghost".to_string(), "poison".to_string()]);
+
+        mock_pokemon_repo.expect_get_pokemon().never();
+        mock_pokemon_repo
+            .expect_get_pokemon_details()
+            .times(1)
+            .returning(move |_| Ok(gastly.clone()));
+
+        mock_translation_repo
+            .expect_translate()
+            .with(eq(Translator::Sith), always())
+            .times(1)
+            .returning(|_, _| translated("Gas pokemon, you will obey"));
+
+        let policy = RuleBasedTranslationPolicy::new(
+            vec![TranslationRule {
+                conditions: RuleConditions {
+                    pokemon_type: Some("ghost".to_string()),
+                    ..RuleConditions::default()
+                },
+                translator: Translator::Sith,
+            }],
+            Translator::Shakespeare,
+        );
+
+        let use_case = PokemonUseCases::new(
+            Arc::new(mock_pokemon_repo),
+            Arc::new(mock_translation_repo),
+            Arc::new(policy),
+        );
+
+        let result = use_case
+            .get_pokemon_translated("gastly", None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.description(), "Gas pokemon, you will obey");
     }
 }
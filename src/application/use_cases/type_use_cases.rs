@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        app_error::{AppError, AppResult},
+        traits::{PokemonRepository, TypeRepository},
+    },
+    domain::{
+        entities::pokemon::Pokemon,
+        services::type_chart::{DefensiveProfile, TypeMatchups},
+    },
+};
+
+#[derive(Clone)]
+pub struct TypeUseCases {
+    pokemon_repository: Arc<dyn PokemonRepository>,
+    type_repository: Arc<dyn TypeRepository>,
+}
+
+impl TypeUseCases {
+    pub fn new(
+        pokemon_repository: Arc<dyn PokemonRepository>,
+        type_repository: Arc<dyn TypeRepository>,
+    ) -> Self {
+        Self {
+            pokemon_repository,
+            type_repository,
+        }
+    }
+
+    pub async fn get_matchups(&self, type_name: &str) -> AppResult<TypeMatchups> {
+        self.type_repository
+            .get_type_chart()
+            .await?
+            .matchups(type_name)
+            .ok_or_else(|| AppError::NotFound(format!("Type '{}' not found", type_name)))
+    }
+
+    /// The Pokemon, or the variety `name` refers to, and what each attacking
+    /// type does against its types.
+    pub async fn get_weaknesses(&self, name: &str) -> AppResult<(Pokemon, DefensiveProfile)> {
+        let pokemon = self.pokemon_repository.get_pokemon_details(name).await?;
+        let chart = self.type_repository.get_type_chart().await?;
+
+        let profile = chart.defensive_profile(pokemon.types());
+        Ok((pokemon, profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::application::traits::{MockPokemonRepository, MockTypeRepository};
+    use crate::domain::services::type_chart::{DamageRelations, TypeChart};
+    use mockall::predicate::*;
+
+    fn chart() -> TypeChart {
+        TypeChart::new(HashMap::from([(
+            "ground".to_string(),
+            DamageRelations {
+                double_damage_to: vec!["electric".to_string()],
+                ..Default::default()
+            },
+        )]))
+    }
+
+    #[tokio::test]
+    async fn test_weaknesses_use_the_types_of_the_detailed_pokemon() {
+        let mut mock_pokemon_repo = MockPokemonRepository::new();
+        let mut mock_type_repo = MockTypeRepository::new();
+
+        mock_pokemon_repo
+            .expect_get_pokemon_details()
+            .with(eq("pikachu"))
+            .times(1)
+            .returning(|_| {
+                Ok(Pokemon::new(
+                    "pikachu".to_string(),
+                    "Mouse".to_string(),
+                    "forest".to_string(),
+                    false,
+                )
+                .with_types(vec!["electric".to_string()]))
+            });
+        mock_type_repo
+            .expect_get_type_chart()
+            .returning(|| Ok(chart()));
+
+        let use_case = TypeUseCases::new(Arc::new(mock_pokemon_repo), Arc::new(mock_type_repo));
+        let (pokemon, profile) = use_case.get_weaknesses("pikachu").await.unwrap();
+
+        assert_eq!(pokemon.name(), "pikachu");
+        let weaknesses = profile.weaknesses();
+        assert_eq!(weaknesses.len(), 1);
+        assert_eq!(weaknesses[0].type_name, "ground");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_type_is_not_found() {
+        let mut mock_type_repo = MockTypeRepository::new();
+        mock_type_repo
+            .expect_get_type_chart()
+            .returning(|| Ok(chart()));
+
+        let use_case = TypeUseCases::new(
+            Arc::new(MockPokemonRepository::new()),
+            Arc::new(mock_type_repo),
+        );
+
+        assert!(matches!(
+            use_case.get_matchups("shadow").await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::value_objects::{
+    effect_text::{EffectText, select_effect},
+    language::LanguagePreference,
+};
+
+/// A Pokemon that can have an ability.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbilityHolder {
+    pub name: String,
+    pub is_hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbilityDetails {
+    name: String,
+    effects: Vec<EffectText>,
+    pokemon: Vec<AbilityHolder>,
+}
+
+impl AbilityDetails {
+    pub fn new(name: String, effects: Vec<EffectText>, pokemon: Vec<AbilityHolder>) -> Self {
+        Self {
+            name,
+            effects,
+            pokemon,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn effect(&self, preference: &LanguagePreference) -> Option<&EffectText> {
+        select_effect(&self.effects, preference)
+    }
+
+    pub fn pokemon(&self) -> &[AbilityHolder] {
+        &self.pokemon
+    }
+}
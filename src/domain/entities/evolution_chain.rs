@@ -0,0 +1,64 @@
+use crate::domain::value_objects::evolution_condition::EvolutionCondition;
+
+/// A species in an evolution chain and the stages it can evolve into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvolutionStage {
+    species: String,
+    is_baby: bool,
+    conditions: Vec<EvolutionCondition>,
+    evolves_to: Vec<EvolutionStage>,
+}
+
+impl EvolutionStage {
+    pub fn new(
+        species: String,
+        is_baby: bool,
+        conditions: Vec<EvolutionCondition>,
+        evolves_to: Vec<EvolutionStage>,
+    ) -> Self {
+        Self {
+            species,
+            is_baby,
+            conditions,
+            evolves_to,
+        }
+    }
+
+    pub fn species(&self) -> &str {
+        &self.species
+    }
+
+    pub fn is_baby(&self) -> bool {
+        self.is_baby
+    }
+
+    /// Ways of evolving into this stage from the previous one. Empty for the
+    /// first stage of a chain.
+    pub fn conditions(&self) -> &[EvolutionCondition] {
+        &self.conditions
+    }
+
+    pub fn evolves_to(&self) -> &[EvolutionStage] {
+        &self.evolves_to
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvolutionChain {
+    id: u32,
+    root: EvolutionStage,
+}
+
+impl EvolutionChain {
+    pub fn new(id: u32, root: EvolutionStage) -> Self {
+        Self { id, root }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn root(&self) -> &EvolutionStage {
+        &self.root
+    }
+}
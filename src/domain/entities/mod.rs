@@ -1 +1,5 @@
+pub mod ability_details;
+pub mod evolution_chain;
+pub mod move_details;
 pub mod pokemon;
+pub mod species_index;
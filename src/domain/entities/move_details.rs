@@ -0,0 +1,109 @@
+use crate::domain::value_objects::{
+    effect_text::{EffectText, select_effect},
+    language::LanguagePreference,
+};
+
+/// Numbers PokéAPI leaves unset for moves they don't apply to, e.g. `power`
+/// for status moves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MoveStats {
+    pub power: Option<u16>,
+    pub accuracy: Option<u8>,
+    pub pp: Option<u8>,
+    pub priority: i8,
+    /// Percent chance of the secondary effect, e.g. 10 for Thunderbolt's
+    /// paralysis.
+    pub effect_chance: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveDetails {
+    name: String,
+    move_type: String,
+    damage_class: String,
+    stats: MoveStats,
+    effects: Vec<EffectText>,
+}
+
+impl MoveDetails {
+    pub fn new(
+        name: String,
+        move_type: String,
+        damage_class: String,
+        stats: MoveStats,
+        effects: Vec<EffectText>,
+    ) -> Self {
+        Self {
+            name,
+            move_type,
+            damage_class,
+            stats,
+            effects,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn move_type(&self) -> &str {
+        &self.move_type
+    }
+
+    /// `physical`, `special` or `status`.
+    pub fn damage_class(&self) -> &str {
+        &self.damage_class
+    }
+
+    pub fn stats(&self) -> &MoveStats {
+        &self.stats
+    }
+
+    /// The effect in the preferred language, with PokéAPI's `$effect_chance`
+    /// placeholder filled in.
+    pub fn effect(&self, preference: &LanguagePreference) -> Option<EffectText> {
+        let chance = self
+            .stats
+            .effect_chance
+            .map(|chance| chance.to_string())
+            .unwrap_or_default();
+        let fill = |text: &str| text.replace("$effect_chance", &chance);
+
+        select_effect(&self.effects, preference).map(|effect| EffectText {
+            language: effect.language.clone(),
+            effect: fill(&effect.effect),
+            short_effect: fill(&effect.short_effect),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effect_fills_in_the_effect_chance() {
+        let thunderbolt = MoveDetails::new(
+            "thunderbolt".to_string(),
+            "electric".to_string(),
+            "special".to_string(),
+            MoveStats {
+                effect_chance: Some(10),
+                ..Default::default()
+            },
+            vec![EffectText {
+                language: "en".to_string(),
+                effect: "Has a $effect_chance% chance to paralyze the target.".to_string(),
+                short_effect: "$effect_chance% chance to paralyze.".to_string(),
+            }],
+        );
+
+        let effect = thunderbolt
+            .effect(&LanguagePreference::single("fr"))
+            .unwrap();
+
+        assert_eq!(effect.language, "en");
+        assert_eq!(effect.effect, "Has a 10% chance to paralyze the target.");
+        assert_eq!(effect.short_effect, "10% chance to paralyze.");
+    }
+}
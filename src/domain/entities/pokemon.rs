@@ -1,21 +1,226 @@
+use crate::domain::value_objects::{
+    ability::Ability,
+    base_stats::BaseStats,
+    egg_group::EggGroup,
+    generation::Generation,
+    growth_rate::GrowthRate,
+    language::{DEFAULT_LANGUAGE, LanguagePreference},
+    learned_move::{LearnedMove, LearnsetFilter},
+    localized_text::{FlavorText, FlavorTextGroup, LocalizedText, group_flavor_texts},
+    pokedex_number::PokedexNumber,
+    species_rarity::SpeciesRarity,
+    sprites::Sprites,
+    translation::{FallbackReason, Translation, TranslationDetails},
+    translator::Translator,
+    variety::Variety,
+};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pokemon {
+    id: Option<u32>,
     name: String,
     description: String,
     habitat: String,
     is_legendary: bool,
+    is_mythical: bool,
+    is_baby: bool,
+    generation: Option<Generation>,
+    genus: Option<String>,
+    color: Option<String>,
+    shape: Option<String>,
+    growth_rate: Option<GrowthRate>,
+    egg_groups: Vec<EggGroup>,
+    capture_rate: Option<u8>,
+    varieties: Vec<Variety>,
+    variety: Option<String>,
+    pokedex_numbers: Vec<PokedexNumber>,
+    evolution_chain_id: Option<u32>,
+    evolves_from: Option<String>,
+    language: String,
+    /// Whether genus or display name fell back to another language.
+    mixed_languages: bool,
+    display_name: Option<String>,
+    names: Vec<LocalizedText>,
+    genera: Vec<LocalizedText>,
+    flavor_texts: Vec<FlavorText>,
+    types: Vec<String>,
+    stats: Option<BaseStats>,
+    abilities: Vec<Ability>,
+    learnset: Vec<LearnedMove>,
+    height: Option<u32>,
+    weight: Option<u32>,
+    sprites: Option<Sprites>,
+    translation: Option<TranslationDetails>,
 }
 
 impl Pokemon {
     pub fn new(name: String, description: String, habitat: String, is_legendary: bool) -> Self {
         Self {
+            id: None,
             name,
             description,
             habitat,
             is_legendary,
+            is_mythical: false,
+            is_baby: false,
+            generation: None,
+            genus: None,
+            color: None,
+            shape: None,
+            growth_rate: None,
+            egg_groups: Vec::new(),
+            capture_rate: None,
+            varieties: Vec::new(),
+            variety: None,
+            pokedex_numbers: Vec::new(),
+            evolution_chain_id: None,
+            evolves_from: None,
+            language: DEFAULT_LANGUAGE.to_string(),
+            mixed_languages: false,
+            display_name: None,
+            names: Vec::new(),
+            genera: Vec::new(),
+            flavor_texts: Vec::new(),
+            types: Vec::new(),
+            stats: None,
+            abilities: Vec::new(),
+            learnset: Vec::new(),
+            height: None,
+            weight: None,
+            sprites: None,
+            translation: None,
         }
     }
 
+    pub fn with_id(mut self, id: u32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_mythical(mut self, is_mythical: bool) -> Self {
+        self.is_mythical = is_mythical;
+        self
+    }
+
+    pub fn with_baby(mut self, is_baby: bool) -> Self {
+        self.is_baby = is_baby;
+        self
+    }
+
+    pub fn with_generation(mut self, generation: Generation) -> Self {
+        self.generation = Some(generation);
+        self
+    }
+
+    pub fn with_genus(mut self, genus: String) -> Self {
+        self.genus = Some(genus);
+        self
+    }
+
+    pub fn with_color(mut self, color: String) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_shape(mut self, shape: String) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    pub fn with_growth_rate(mut self, growth_rate: GrowthRate) -> Self {
+        self.growth_rate = Some(growth_rate);
+        self
+    }
+
+    pub fn with_egg_groups(mut self, egg_groups: Vec<EggGroup>) -> Self {
+        self.egg_groups = egg_groups;
+        self
+    }
+
+    pub fn with_capture_rate(mut self, capture_rate: u8) -> Self {
+        self.capture_rate = Some(capture_rate);
+        self
+    }
+
+    pub fn with_varieties(mut self, varieties: Vec<Variety>) -> Self {
+        self.varieties = varieties;
+        self
+    }
+
+    /// The variety that was looked up, when not the species' default one.
+    pub fn with_variety(mut self, variety: String) -> Self {
+        self.variety = Some(variety);
+        self
+    }
+
+    pub fn with_pokedex_numbers(mut self, pokedex_numbers: Vec<PokedexNumber>) -> Self {
+        self.pokedex_numbers = pokedex_numbers;
+        self
+    }
+
+    pub fn with_evolution_chain_id(mut self, evolution_chain_id: u32) -> Self {
+        self.evolution_chain_id = Some(evolution_chain_id);
+        self
+    }
+
+    pub fn with_evolves_from(mut self, species: String) -> Self {
+        self.evolves_from = Some(species);
+        self
+    }
+
+    /// Localized species names, e.g. `Pikachu` / `ピカチュウ`.
+    pub fn with_names(mut self, names: Vec<LocalizedText>) -> Self {
+        self.display_name = find_text(&names, DEFAULT_LANGUAGE);
+        self.names = names;
+        self
+    }
+
+    pub fn with_genera(mut self, genera: Vec<LocalizedText>) -> Self {
+        self.genera = genera;
+        self
+    }
+
+    pub fn with_flavor_texts(mut self, flavor_texts: Vec<FlavorText>) -> Self {
+        self.flavor_texts = flavor_texts;
+        self
+    }
+
+    pub fn with_types(mut self, types: Vec<String>) -> Self {
+        self.types = types;
+        self
+    }
+
+    pub fn with_stats(mut self, stats: BaseStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    pub fn with_abilities(mut self, abilities: Vec<Ability>) -> Self {
+        self.abilities = abilities;
+        self
+    }
+
+    pub fn with_learnset(mut self, learnset: Vec<LearnedMove>) -> Self {
+        self.learnset = learnset;
+        self
+    }
+
+    /// Height in decimetres and weight in hectograms, as reported by PokéAPI.
+    pub fn with_size(mut self, height: u32, weight: u32) -> Self {
+        self.height = Some(height);
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn with_sprites(mut self, sprites: Sprites) -> Self {
+        self.sprites = Some(sprites);
+        self
+    }
+
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -32,13 +237,238 @@ impl Pokemon {
         self.is_legendary
     }
 
+    pub fn is_mythical(&self) -> bool {
+        self.is_mythical
+    }
+
+    pub fn rarity(&self) -> SpeciesRarity {
+        SpeciesRarity {
+            is_legendary: self.is_legendary,
+            is_mythical: self.is_mythical,
+        }
+    }
+
+    pub fn is_baby(&self) -> bool {
+        self.is_baby
+    }
+
+    pub fn generation(&self) -> Option<Generation> {
+        self.generation
+    }
+
+    pub fn genus(&self) -> Option<&str> {
+        self.genus.as_deref()
+    }
+
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    pub fn shape(&self) -> Option<&str> {
+        self.shape.as_deref()
+    }
+
+    pub fn growth_rate(&self) -> Option<GrowthRate> {
+        self.growth_rate
+    }
+
+    pub fn egg_groups(&self) -> &[EggGroup] {
+        &self.egg_groups
+    }
+
+    pub fn capture_rate(&self) -> Option<u8> {
+        self.capture_rate
+    }
+
+    pub fn varieties(&self) -> &[Variety] {
+        &self.varieties
+    }
+
+    /// The looked up variety, falling back to the species' default one.
+    pub fn variety(&self) -> Option<&Variety> {
+        match &self.variety {
+            Some(name) => self.varieties.iter().find(|variety| &variety.name == name),
+            None => self.varieties.iter().find(|variety| variety.is_default),
+        }
+    }
+
+    pub fn pokedex_numbers(&self) -> &[PokedexNumber] {
+        &self.pokedex_numbers
+    }
+
+    pub fn evolution_chain_id(&self) -> Option<u32> {
+        self.evolution_chain_id
+    }
+
+    pub fn evolves_from(&self) -> Option<&str> {
+        self.evolves_from.as_deref()
+    }
+
+    /// Language of `description`, and of `genus` and `display_name` unless
+    /// they fell back to another one.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// `language`, when `description`, `genus` and `display_name` all use it.
+    pub fn content_language(&self) -> Option<&str> {
+        (!self.mixed_languages).then_some(self.language.as_str())
+    }
+
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    pub fn names(&self) -> &[LocalizedText] {
+        &self.names
+    }
+
+    pub fn flavor_texts(&self) -> &[FlavorText] {
+        &self.flavor_texts
+    }
+
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+
+    pub fn stats(&self) -> Option<&BaseStats> {
+        self.stats.as_ref()
+    }
+
+    pub fn abilities(&self) -> &[Ability] {
+        &self.abilities
+    }
+
+    /// Moves matching `filter`, grouped by learn method and in level order
+    /// within each.
+    pub fn learnset(&self, filter: &LearnsetFilter) -> Vec<&LearnedMove> {
+        let mut learnset: Vec<&LearnedMove> = self
+            .learnset
+            .iter()
+            .filter(|learned| filter.matches(learned))
+            .collect();
+        learnset.sort_by(|a, b| {
+            (&a.method, a.level, &a.name, &a.version_group).cmp(&(
+                &b.method,
+                b.level,
+                &b.name,
+                &b.version_group,
+            ))
+        });
+        learnset
+    }
+
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    pub fn weight(&self) -> Option<u32> {
+        self.weight
+    }
+
+    pub fn sprites(&self) -> Option<&Sprites> {
+        self.sprites.as_ref()
+    }
+
+    /// Set once a translation has been attempted, whether or not it succeeded.
+    pub fn translation(&self) -> Option<&TranslationDetails> {
+        self.translation.as_ref()
+    }
+
+    /// Compares habitats ignoring case and `-`/space differences, so "rare-cave"
+    /// matches the display form "Rare Cave".
+    pub fn has_habitat(&self, habitat: &str) -> bool {
+        normalize_habitat(&self.habitat) == normalize_habitat(habitat)
+    }
+
     pub fn is_cave(&self) -> bool {
-        self.habitat == "cave"
+        self.has_habitat("cave")
+    }
+
+    /// All flavor texts with identical texts of the same language merged.
+    pub fn flavor_text_groups(&self) -> Vec<FlavorTextGroup> {
+        group_flavor_texts(&self.flavor_texts)
+    }
+
+    pub fn has_flavor_text_for(&self, version: &str) -> bool {
+        self.flavor_texts
+            .iter()
+            .any(|entry| entry.version.eq_ignore_ascii_case(version))
+    }
+
+    /// Switches description, genus and display name to the best language
+    /// available in the flavor texts, optionally only considering those of one
+    /// game `version`. Name and genus fall back to what they were when missing
+    /// in that language.
+    pub fn localize(&mut self, preference: &LanguagePreference, version: Option<&str>) {
+        let candidates: Vec<&FlavorText> = self
+            .flavor_texts
+            .iter()
+            .filter(|entry| version.is_none_or(|v| entry.version.eq_ignore_ascii_case(v)))
+            .collect();
+
+        let mut available: Vec<&str> = Vec::new();
+        for entry in &candidates {
+            if !available.contains(&entry.language.as_str()) {
+                available.push(&entry.language);
+            }
+        }
+
+        let Some(language) = preference.best_match(&available).map(str::to_string) else {
+            return;
+        };
+
+        if let Some(entry) = candidates.iter().find(|e| e.language == language) {
+            self.description = entry.text.clone();
+        }
+        let genus = find_text(&self.genera, &language);
+        let name = find_text(&self.names, &language);
+        self.mixed_languages = (genus.is_none() && self.genus.is_some())
+            || (name.is_none() && self.display_name.is_some());
+        self.genus = genus.or(self.genus.take());
+        self.display_name = name.or(self.display_name.take());
+        self.language = language;
     }
 
     pub fn set_translated_description(&mut self, new_description: String) {
         self.description = new_description;
     }
+
+    /// Replaces the description with `outcome`'s text, or keeps it on fallback,
+    /// recording the original description either way.
+    pub fn apply_translation(
+        &mut self,
+        translator: Translator,
+        outcome: Result<Translation, FallbackReason>,
+    ) {
+        let original_description = self.description.clone();
+
+        let (from_cache, fallback_reason) = match outcome {
+            Ok(translation) => {
+                self.set_translated_description(translation.text);
+                (translation.from_cache, None)
+            }
+            Err(reason) => (false, Some(reason)),
+        };
+
+        self.translation = Some(TranslationDetails {
+            original_description,
+            translator,
+            from_cache,
+            fallback_reason,
+        });
+    }
+}
+
+fn find_text(texts: &[LocalizedText], language: &str) -> Option<String> {
+    texts
+        .iter()
+        .find(|entry| entry.language == language)
+        .map(|entry| entry.text.clone())
+}
+
+fn normalize_habitat(habitat: &str) -> String {
+    habitat.trim().to_lowercase().replace('-', " ")
 }
 
 #[cfg(test)]
@@ -96,4 +526,152 @@ mod tests {
 
         assert!(legendary.is_legendary());
     }
+
+    #[test]
+    fn test_has_habitat_ignores_case_and_separators() {
+        let pokemon = Pokemon::new(
+            "Zubat".to_string(),
+            "Bat".to_string(),
+            "Rare Cave".to_string(),
+            false,
+        );
+
+        assert!(pokemon.has_habitat("rare-cave"));
+        assert!(!pokemon.has_habitat("cave"));
+    }
+
+    #[test]
+    fn test_apply_translation_keeps_original_description() {
+        let mut pokemon = Pokemon::new(
+            "Zubat".to_string(),
+            "Bat".to_string(),
+            "cave".to_string(),
+            false,
+        );
+
+        pokemon.apply_translation(
+            Translator::Yoda,
+            Ok(Translation {
+                text: "Bat, it is".to_string(),
+                from_cache: true,
+            }),
+        );
+
+        let details = pokemon.translation().unwrap();
+        assert_eq!(pokemon.description(), "Bat, it is");
+        assert_eq!(details.original_description, "Bat");
+        assert!(details.from_cache);
+        assert_eq!(details.fallback_reason, None);
+    }
+
+    #[test]
+    fn test_apply_translation_fallback_keeps_description() {
+        let mut pokemon = Pokemon::new(
+            "Zubat".to_string(),
+            "Bat".to_string(),
+            "cave".to_string(),
+            false,
+        );
+
+        pokemon.apply_translation(Translator::Yoda, Err(FallbackReason::RateLimited));
+
+        let details = pokemon.translation().unwrap();
+        assert_eq!(pokemon.description(), "Bat");
+        assert_eq!(details.translator, Translator::Yoda);
+        assert_eq!(details.fallback_reason, Some(FallbackReason::RateLimited));
+    }
+
+    #[test]
+    fn test_localize_switches_to_preferred_language() {
+        let text = |language: &str, text: &str| LocalizedText {
+            language: language.to_string(),
+            text: text.to_string(),
+        };
+        let flavor = |language: &str, text: &str| FlavorText {
+            language: language.to_string(),
+            version: "red".to_string(),
+            text: text.to_string(),
+        };
+
+        let mut pokemon = Pokemon::new(
+            "pikachu".to_string(),
+            "Electric mouse".to_string(),
+            "Forest".to_string(),
+            false,
+        )
+        .with_genus("Mouse Pokémon".to_string())
+        .with_names(vec![text("en", "Pikachu"), text("fr", "Pikachu FR")])
+        .with_genera(vec![text("en", "Mouse Pokémon")])
+        .with_flavor_texts(vec![
+            flavor("en", "Electric mouse"),
+            flavor("fr", "Souris électrique"),
+        ]);
+
+        pokemon.localize(
+            &LanguagePreference::from_accept_language("fr-FR, en;q=0.5"),
+            None,
+        );
+
+        assert_eq!(pokemon.language(), "fr");
+        assert_eq!(pokemon.description(), "Souris électrique");
+        assert_eq!(pokemon.display_name(), Some("Pikachu FR"));
+        assert_eq!(pokemon.genus(), Some("Mouse Pokémon"));
+        assert_eq!(pokemon.content_language(), None);
+
+        pokemon.localize(&LanguagePreference::from_accept_language("en"), None);
+        assert_eq!(pokemon.content_language(), Some("en"));
+    }
+
+    #[test]
+    fn test_localize_picks_requested_version() {
+        let flavor = |version: &str, text: &str| FlavorText {
+            language: "en".to_string(),
+            version: version.to_string(),
+            text: text.to_string(),
+        };
+
+        let mut pokemon = Pokemon::new(
+            "pikachu".to_string(),
+            "From red".to_string(),
+            "Forest".to_string(),
+            false,
+        )
+        .with_flavor_texts(vec![
+            flavor("red", "From red"),
+            flavor("yellow", "From yellow"),
+        ]);
+
+        assert!(pokemon.has_flavor_text_for("Yellow"));
+        assert!(!pokemon.has_flavor_text_for("sword"));
+
+        pokemon.localize(&LanguagePreference::default(), Some("yellow"));
+
+        assert_eq!(pokemon.description(), "From yellow");
+    }
+
+    #[test]
+    fn test_variety_defaults_to_the_default_one() {
+        let pokemon = Pokemon::new(
+            "giratina".to_string(),
+            "Renegade".to_string(),
+            "rare".to_string(),
+            true,
+        )
+        .with_varieties(vec![
+            Variety {
+                name: "giratina-altered".to_string(),
+                is_default: true,
+            },
+            Variety {
+                name: "giratina-origin".to_string(),
+                is_default: false,
+            },
+        ]);
+
+        assert_eq!(pokemon.variety().unwrap().name, "giratina-altered");
+
+        let origin = pokemon.with_variety("giratina-origin".to_string());
+        assert_eq!(origin.variety().unwrap().name, "giratina-origin");
+        assert!(!origin.variety().unwrap().is_default);
+    }
 }
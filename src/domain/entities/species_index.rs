@@ -0,0 +1,190 @@
+use crate::domain::value_objects::localized_text::LocalizedText;
+
+/// A species and the localized names it is known to have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedSpecies {
+    pub name: String,
+    pub names: Vec<LocalizedText>,
+}
+
+/// A species found by `SpeciesIndex::search`, with the name that matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub species: String,
+    pub matched_name: String,
+    /// Language of `matched_name`, `None` when the species name matched.
+    pub language: Option<String>,
+}
+
+/// Every known species name, used to search and to suggest corrections for
+/// misspelled names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpeciesIndex {
+    species: Vec<IndexedSpecies>,
+}
+
+impl SpeciesIndex {
+    pub fn new(species: Vec<IndexedSpecies>) -> Self {
+        Self { species }
+    }
+
+    /// Species whose name, or one of their localized names, is within a few
+    /// edits of `name`, closest first.
+    pub fn suggest(&self, name: &str, limit: usize) -> Vec<String> {
+        let name = name.trim().to_lowercase();
+        let max_distance = max_distance(&name);
+
+        let mut suggestions: Vec<(usize, &str)> = self
+            .species
+            .iter()
+            .filter_map(|species| {
+                candidates(species)
+                    .map(|(candidate, _)| edit_distance(&name, &candidate.to_lowercase()))
+                    .min()
+                    .filter(|distance| *distance <= max_distance)
+                    .map(|distance| (distance, species.name.as_str()))
+            })
+            .collect();
+        suggestions.sort();
+
+        suggestions
+            .into_iter()
+            .take(limit)
+            .map(|(_, species)| species.to_string())
+            .collect()
+    }
+
+    /// Species with a name starting with `query`, followed by those with a
+    /// name or name prefix within a few edits of it.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchMatch> {
+        let query = query.trim().to_lowercase();
+        let max_distance = max_distance(&query);
+
+        let mut matches: Vec<((bool, usize), SearchMatch)> = self
+            .species
+            .iter()
+            .filter_map(|species| {
+                candidates(species)
+                    .filter_map(|(candidate, language)| {
+                        let score = match_score(&query, &candidate.to_lowercase(), max_distance)?;
+                        Some((
+                            score,
+                            SearchMatch {
+                                species: species.name.clone(),
+                                matched_name: candidate.to_string(),
+                                language: language.map(str::to_string),
+                            },
+                        ))
+                    })
+                    .min_by_key(|(score, _)| *score)
+            })
+            .collect();
+        matches.sort_by(|(a, a_match), (b, b_match)| {
+            a.cmp(b).then_with(|| a_match.species.cmp(&b_match.species))
+        });
+
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, found)| found)
+            .collect()
+    }
+}
+
+/// The species name followed by its localized names.
+fn candidates(species: &IndexedSpecies) -> impl Iterator<Item = (&str, Option<&str>)> {
+    std::iter::once((species.name.as_str(), None)).chain(
+        species
+            .names
+            .iter()
+            .map(|name| (name.text.as_str(), Some(name.language.as_str()))),
+    )
+}
+
+/// Prefix matches sort before fuzzy ones; within each, closer matches first.
+fn match_score(query: &str, candidate: &str, max_distance: usize) -> Option<(bool, usize)> {
+    if candidate.starts_with(query) {
+        return Some((false, candidate.chars().count() - query.chars().count()));
+    }
+
+    let prefix: String = candidate.chars().take(query.chars().count()).collect();
+    let distance = edit_distance(query, candidate).min(edit_distance(query, &prefix));
+    (distance <= max_distance).then_some((true, distance))
+}
+
+fn max_distance(query: &str) -> usize {
+    (query.chars().count() / 3).clamp(1, 3)
+}
+
+/// Levenshtein distance over characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SpeciesIndex {
+        let species = |name: &str, names: &[(&str, &str)]| IndexedSpecies {
+            name: name.to_string(),
+            names: names
+                .iter()
+                .map(|(language, text)| LocalizedText {
+                    language: language.to_string(),
+                    text: text.to_string(),
+                })
+                .collect(),
+        };
+
+        SpeciesIndex::new(vec![
+            species("pichu", &[]),
+            species("pikachu", &[("en", "Pikachu"), ("ja-Hrkt", "ピカチュウ")]),
+            species("raichu", &[]),
+            species("mr-mime", &[("fr", "M. Mime")]),
+        ])
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("pikachu", "pikachu"), 0);
+        assert_eq!(edit_distance("pikchu", "pikachu"), 1);
+        assert_eq!(edit_distance("raichu", "pichu"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest_returns_species_within_a_few_edits() {
+        assert_eq!(index().suggest("pikacu", 5), vec!["pikachu"]);
+        assert_eq!(index().suggest("pikchu", 5), vec!["pichu", "pikachu"]);
+        assert_eq!(index().suggest("raichuuu", 5), vec!["raichu"]);
+        assert!(index().suggest("bulbasaur", 5).is_empty());
+    }
+
+    #[test]
+    fn test_search_prefers_prefix_matches_and_covers_localized_names() {
+        let results = index().search("pi", 10);
+        let species: Vec<_> = results.iter().map(|m| m.species.as_str()).collect();
+        assert_eq!(species, ["pichu", "pikachu"]);
+
+        let results = index().search("ピカ", 10);
+        assert_eq!(results[0].species, "pikachu");
+        assert_eq!(results[0].language.as_deref(), Some("ja-Hrkt"));
+
+        let results = index().search("rai", 10);
+        assert_eq!(results[0].species, "raichu");
+        assert_eq!(results[0].language, None);
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+/// The types of the main series games, in PokéAPI's order.
+pub const TYPES: [&str; 18] = [
+    "normal", "fighting", "flying", "poison", "ground", "rock", "bug", "ghost", "steel", "fire",
+    "water", "grass", "electric", "psychic", "ice", "dragon", "dark", "fairy",
+];
+
+/// Types an attacking type deals double, half or no damage to. Any other
+/// type takes normal damage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DamageRelations {
+    pub double_damage_to: Vec<String>,
+    pub half_damage_to: Vec<String>,
+    pub no_damage_to: Vec<String>,
+}
+
+/// The damage multiplier of a move of one type against another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeMultiplier {
+    pub type_name: String,
+    pub multiplier: f32,
+}
+
+/// Types a type hits, and is hit by, for other than normal damage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeMatchups {
+    pub attacking: Vec<TypeMultiplier>,
+    pub defending: Vec<TypeMultiplier>,
+}
+
+/// How much damage each attacking type deals to a Pokemon of the given
+/// types, e.g. 4x for Ice against Dragon/Flying.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefensiveProfile {
+    pub types: Vec<String>,
+    pub multipliers: Vec<TypeMultiplier>,
+}
+
+impl DefensiveProfile {
+    /// Most effective first.
+    pub fn weaknesses(&self) -> Vec<&TypeMultiplier> {
+        let mut weaknesses: Vec<_> = self.matching(|m| m > 1.0).collect();
+        weaknesses.sort_by(|a, b| b.multiplier.total_cmp(&a.multiplier));
+        weaknesses
+    }
+
+    /// Least effective first.
+    pub fn resistances(&self) -> Vec<&TypeMultiplier> {
+        let mut resistances: Vec<_> = self.matching(|m| m > 0.0 && m < 1.0).collect();
+        resistances.sort_by(|a, b| a.multiplier.total_cmp(&b.multiplier));
+        resistances
+    }
+
+    pub fn immunities(&self) -> Vec<&TypeMultiplier> {
+        self.matching(|m| m == 0.0).collect()
+    }
+
+    fn matching(&self, keep: impl Fn(f32) -> bool) -> impl Iterator<Item = &TypeMultiplier> {
+        self.multipliers.iter().filter(move |m| keep(m.multiplier))
+    }
+}
+
+/// Damage relations of every type, keyed by attacking type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeChart {
+    relations: HashMap<String, DamageRelations>,
+}
+
+impl TypeChart {
+    pub fn new(relations: HashMap<String, DamageRelations>) -> Self {
+        Self { relations }
+    }
+
+    pub fn contains(&self, type_name: &str) -> bool {
+        self.relations.contains_key(type_name)
+    }
+
+    /// 2, 0.5 or 0, and 1 for any pair the chart says nothing about.
+    pub fn multiplier(&self, attacking: &str, defending: &str) -> f32 {
+        let Some(relations) = self.relations.get(attacking) else {
+            return 1.0;
+        };
+        let hits = |types: &[String]| types.iter().any(|t| t == defending);
+
+        if hits(&relations.no_damage_to) {
+            0.0
+        } else if hits(&relations.double_damage_to) {
+            2.0
+        } else if hits(&relations.half_damage_to) {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// `None` for a type the chart doesn't know.
+    pub fn matchups(&self, type_name: &str) -> Option<TypeMatchups> {
+        if !self.contains(type_name) {
+            return None;
+        }
+
+        let attacking = TYPES
+            .iter()
+            .map(|defending| TypeMultiplier {
+                type_name: defending.to_string(),
+                multiplier: self.multiplier(type_name, defending),
+            })
+            .filter(|m| m.multiplier != 1.0)
+            .collect();
+        let defending = self
+            .defensive_profile(&[type_name.to_string()])
+            .multipliers
+            .into_iter()
+            .filter(|m| m.multiplier != 1.0)
+            .collect();
+
+        Some(TypeMatchups {
+            attacking,
+            defending,
+        })
+    }
+
+    /// Each attacking type's multiplier is the product of its multipliers
+    /// against every one of `types`.
+    pub fn defensive_profile(&self, types: &[String]) -> DefensiveProfile {
+        let multipliers = TYPES
+            .iter()
+            .map(|attacking| TypeMultiplier {
+                type_name: attacking.to_string(),
+                multiplier: types
+                    .iter()
+                    .map(|defending| self.multiplier(attacking, defending))
+                    .product(),
+            })
+            .collect();
+
+        DefensiveProfile {
+            types: types.to_vec(),
+            multipliers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart() -> TypeChart {
+        let relations = |double: &[&str], half: &[&str], none: &[&str]| {
+            let names = |types: &[&str]| types.iter().map(|t| t.to_string()).collect();
+            DamageRelations {
+                double_damage_to: names(double),
+                half_damage_to: names(half),
+                no_damage_to: names(none),
+            }
+        };
+
+        TypeChart::new(HashMap::from([
+            (
+                "electric".to_string(),
+                relations(
+                    &["flying", "water"],
+                    &["electric", "grass", "dragon"],
+                    &["ground"],
+                ),
+            ),
+            (
+                "ground".to_string(),
+                relations(&["electric", "fire"], &["grass", "bug"], &["flying"]),
+            ),
+            (
+                "ice".to_string(),
+                relations(&["flying", "dragon"], &["ice"], &[]),
+            ),
+            (
+                "rock".to_string(),
+                relations(&["flying", "fire"], &["ground"], &[]),
+            ),
+            (
+                "flying".to_string(),
+                relations(&["grass"], &["electric"], &[]),
+            ),
+            (
+                "dragon".to_string(),
+                relations(&["dragon"], &[], &["fairy"]),
+            ),
+        ]))
+    }
+
+    fn multiplier_of(multipliers: &[&TypeMultiplier], type_name: &str) -> Option<f32> {
+        multipliers
+            .iter()
+            .find(|m| m.type_name == type_name)
+            .map(|m| m.multiplier)
+    }
+
+    #[test]
+    fn test_single_type_multipliers() {
+        let chart = chart();
+
+        assert_eq!(chart.multiplier("electric", "water"), 2.0);
+        assert_eq!(chart.multiplier("electric", "grass"), 0.5);
+        assert_eq!(chart.multiplier("electric", "ground"), 0.0);
+        assert_eq!(chart.multiplier("electric", "normal"), 1.0);
+        assert_eq!(chart.multiplier("shadow", "normal"), 1.0);
+    }
+
+    #[test]
+    fn test_dual_types_multiply_and_immunities_win() {
+        let dragonite = chart().defensive_profile(&["dragon".to_string(), "flying".to_string()]);
+
+        let weaknesses = dragonite.weaknesses();
+        assert_eq!(weaknesses[0].type_name, "ice");
+        assert_eq!(weaknesses[0].multiplier, 4.0);
+        assert_eq!(multiplier_of(&weaknesses, "rock"), Some(2.0));
+        assert_eq!(multiplier_of(&weaknesses, "dragon"), Some(2.0));
+        assert_eq!(multiplier_of(&weaknesses, "electric"), None);
+
+        let immunities = dragonite.immunities();
+        assert_eq!(multiplier_of(&immunities, "ground"), Some(0.0));
+
+        let gastrodon = chart().defensive_profile(&["water".to_string(), "ground".to_string()]);
+        assert_eq!(
+            multiplier_of(&gastrodon.immunities(), "electric"),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_matchups_list_only_non_neutral_types() {
+        let electric = chart().matchups("electric").unwrap();
+
+        let attacking: Vec<_> = electric
+            .attacking
+            .iter()
+            .map(|m| (m.type_name.as_str(), m.multiplier))
+            .collect();
+        assert_eq!(
+            attacking,
+            [
+                ("flying", 2.0),
+                ("ground", 0.0),
+                ("water", 2.0),
+                ("grass", 0.5),
+                ("electric", 0.5),
+                ("dragon", 0.5)
+            ]
+        );
+
+        let defending: Vec<_> = electric
+            .defending
+            .iter()
+            .map(|m| (m.type_name.as_str(), m.multiplier))
+            .collect();
+        assert_eq!(
+            defending,
+            [("flying", 0.5), ("ground", 2.0), ("electric", 0.5)]
+        );
+
+        assert!(chart().matchups("shadow").is_none());
+    }
+}
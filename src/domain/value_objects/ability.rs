@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ability {
+    pub name: String,
+    pub is_hidden: bool,
+    pub slot: u8,
+}
@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BaseStats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub special_attack: u16,
+    pub special_defense: u16,
+    pub speed: u16,
+}
+
+impl BaseStats {
+    pub fn total(&self) -> u16 {
+        self.hp
+            + self.attack
+            + self.defense
+            + self.special_attack
+            + self.special_defense
+            + self.speed
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::value_objects::language::LanguagePreference;
+
+/// What a move or ability does, in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectText {
+    pub language: String,
+    pub effect: String,
+    pub short_effect: String,
+}
+
+/// The effect in the language that best matches `preference`.
+pub fn select_effect<'a>(
+    effects: &'a [EffectText],
+    preference: &LanguagePreference,
+) -> Option<&'a EffectText> {
+    let available: Vec<&str> = effects.iter().map(|e| e.language.as_str()).collect();
+    let language = preference.best_match(&available)?;
+    effects.iter().find(|e| e.language == language)
+}
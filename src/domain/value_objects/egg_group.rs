@@ -0,0 +1,81 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Breeding compatibility group. Pokemon sharing an egg group can breed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EggGroup {
+    Monster,
+    Water1,
+    Water2,
+    Water3,
+    Bug,
+    Flying,
+    Ground,
+    Fairy,
+    Plant,
+    HumanShape,
+    Mineral,
+    Indeterminate,
+    Ditto,
+    Dragon,
+    NoEggs,
+}
+
+impl EggGroup {
+    pub const ALL: [EggGroup; 15] = [
+        EggGroup::Monster,
+        EggGroup::Water1,
+        EggGroup::Water2,
+        EggGroup::Water3,
+        EggGroup::Bug,
+        EggGroup::Flying,
+        EggGroup::Ground,
+        EggGroup::Fairy,
+        EggGroup::Plant,
+        EggGroup::HumanShape,
+        EggGroup::Mineral,
+        EggGroup::Indeterminate,
+        EggGroup::Ditto,
+        EggGroup::Dragon,
+        EggGroup::NoEggs,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EggGroup::Monster => "monster",
+            EggGroup::Water1 => "water1",
+            EggGroup::Water2 => "water2",
+            EggGroup::Water3 => "water3",
+            EggGroup::Bug => "bug",
+            EggGroup::Flying => "flying",
+            EggGroup::Ground => "ground",
+            EggGroup::Fairy => "fairy",
+            EggGroup::Plant => "plant",
+            EggGroup::HumanShape => "humanshape",
+            EggGroup::Mineral => "mineral",
+            EggGroup::Indeterminate => "indeterminate",
+            EggGroup::Ditto => "ditto",
+            EggGroup::Dragon => "dragon",
+            EggGroup::NoEggs => "no-eggs",
+        }
+    }
+}
+
+impl fmt::Display for EggGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EggGroup {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_lowercase();
+
+        EggGroup::ALL
+            .into_iter()
+            .find(|egg_group| egg_group.as_str() == value)
+            .ok_or_else(|| format!("unknown egg group: {}", value))
+    }
+}
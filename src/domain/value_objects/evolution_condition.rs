@@ -0,0 +1,80 @@
+/// What causes an evolution to happen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvolutionTrigger {
+    LevelUp,
+    Trade,
+    UseItem,
+    Shed,
+    Other(String),
+}
+
+impl EvolutionTrigger {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "level-up" => EvolutionTrigger::LevelUp,
+            "trade" => EvolutionTrigger::Trade,
+            "use-item" => EvolutionTrigger::UseItem,
+            "shed" => EvolutionTrigger::Shed,
+            other => EvolutionTrigger::Other(other.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            EvolutionTrigger::LevelUp => "level-up",
+            EvolutionTrigger::Trade => "trade",
+            EvolutionTrigger::UseItem => "use-item",
+            EvolutionTrigger::Shed => "shed",
+            EvolutionTrigger::Other(name) => name,
+        }
+    }
+}
+
+/// One way of reaching an evolution stage: a trigger plus every requirement
+/// that must hold when it fires. Unset requirements don't apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvolutionCondition {
+    pub trigger: EvolutionTrigger,
+    pub min_level: Option<u8>,
+    pub item: Option<String>,
+    pub held_item: Option<String>,
+    pub known_move: Option<String>,
+    pub known_move_type: Option<String>,
+    pub location: Option<String>,
+    pub min_happiness: Option<u8>,
+    pub min_beauty: Option<u8>,
+    pub min_affection: Option<u8>,
+    pub time_of_day: Option<String>,
+    pub gender: Option<u8>,
+    pub needs_overworld_rain: bool,
+    pub party_species: Option<String>,
+    pub party_type: Option<String>,
+    pub relative_physical_stats: Option<i8>,
+    pub trade_species: Option<String>,
+    pub turn_upside_down: bool,
+}
+
+impl EvolutionCondition {
+    pub fn new(trigger: EvolutionTrigger) -> Self {
+        Self {
+            trigger,
+            min_level: None,
+            item: None,
+            held_item: None,
+            known_move: None,
+            known_move_type: None,
+            location: None,
+            min_happiness: None,
+            min_beauty: None,
+            min_affection: None,
+            time_of_day: None,
+            gender: None,
+            needs_overworld_rain: false,
+            party_species: None,
+            party_type: None,
+            relative_physical_stats: None,
+            trade_species: None,
+            turn_upside_down: false,
+        }
+    }
+}
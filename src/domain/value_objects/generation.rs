@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+
+const NUMERALS: [&str; 12] = [
+    "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x", "xi", "xii",
+];
+
+/// A main-series game generation, named by PokéAPI as `generation-<roman>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Generation(u8);
+
+impl Generation {
+    pub fn new(number: u8) -> Option<Self> {
+        (1..=NUMERALS.len() as u8)
+            .contains(&number)
+            .then_some(Generation(number))
+    }
+
+    pub fn number(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Generation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "generation-{}", NUMERALS[usize::from(self.0) - 1])
+    }
+}
+
+impl FromStr for Generation {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_lowercase();
+
+        value
+            .strip_prefix("generation-")
+            .and_then(|numeral| NUMERALS.iter().position(|n| *n == numeral))
+            .map(|index| Generation(index as u8 + 1))
+            .ok_or_else(|| format!("unknown generation: {}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generation_parses_pokeapi_names() {
+        let generation = "generation-iv".parse::<Generation>().unwrap();
+
+        assert_eq!(generation.number(), 4);
+        assert_eq!(generation.to_string(), "generation-iv");
+        assert_eq!(
+            "Generation-IX".parse::<Generation>().ok(),
+            Generation::new(9)
+        );
+    }
+
+    #[test]
+    fn test_generation_rejects_unknown_names() {
+        assert!("generation-0".parse::<Generation>().is_err());
+        assert!("iv".parse::<Generation>().is_err());
+        assert_eq!(Generation::new(0), None);
+    }
+}
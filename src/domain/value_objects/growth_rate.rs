@@ -0,0 +1,54 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How much experience a Pokemon needs to level up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrowthRate {
+    Slow,
+    Medium,
+    Fast,
+    MediumSlow,
+    SlowThenVeryFast,
+    FastThenVerySlow,
+}
+
+impl GrowthRate {
+    pub const ALL: [GrowthRate; 6] = [
+        GrowthRate::Slow,
+        GrowthRate::Medium,
+        GrowthRate::Fast,
+        GrowthRate::MediumSlow,
+        GrowthRate::SlowThenVeryFast,
+        GrowthRate::FastThenVerySlow,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GrowthRate::Slow => "slow",
+            GrowthRate::Medium => "medium",
+            GrowthRate::Fast => "fast",
+            GrowthRate::MediumSlow => "medium-slow",
+            GrowthRate::SlowThenVeryFast => "slow-then-very-fast",
+            GrowthRate::FastThenVerySlow => "fast-then-very-slow",
+        }
+    }
+}
+
+impl fmt::Display for GrowthRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for GrowthRate {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_lowercase();
+
+        GrowthRate::ALL
+            .into_iter()
+            .find(|growth_rate| growth_rate.as_str() == value)
+            .ok_or_else(|| format!("unknown growth rate: {}", value))
+    }
+}
@@ -0,0 +1,103 @@
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Languages a client accepts, most preferred first. Tags are compared
+/// case-insensitively against PokéAPI language names (`en`, `fr`, `ja-Hrkt`,
+/// `zh-Hans`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LanguagePreference {
+    tags: Vec<String>,
+}
+
+impl LanguagePreference {
+    pub fn single(tag: &str) -> Self {
+        Self {
+            tags: vec![tag.trim().to_ascii_lowercase()],
+        }
+    }
+
+    /// Parses an `Accept-Language` header value, ordering tags by q-value.
+    /// Malformed entries, `*` and tags with `q=0` are ignored.
+    pub fn from_accept_language(header: &str) -> Self {
+        let mut weighted: Vec<(String, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let tag = parts.next()?.trim().to_ascii_lowercase();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+
+                (!tag.is_empty() && tag != "*" && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Self {
+            tags: weighted.into_iter().map(|(tag, _)| tag).collect(),
+        }
+    }
+
+    /// Picks the best of `available` languages: for each preferred tag an exact
+    /// match first, then one sharing its primary subtag (`fr-CH` -> `fr`),
+    /// falling back to English.
+    pub fn best_match<'a>(&self, available: &[&'a str]) -> Option<&'a str> {
+        self.tags
+            .iter()
+            .find_map(|tag| {
+                available
+                    .iter()
+                    .find(|language| language.eq_ignore_ascii_case(tag))
+                    .or_else(|| {
+                        available
+                            .iter()
+                            .find(|language| primary_subtag(language) == primary_subtag(tag))
+                    })
+            })
+            .or_else(|| {
+                available
+                    .iter()
+                    .find(|language| language.eq_ignore_ascii_case(DEFAULT_LANGUAGE))
+            })
+            .copied()
+    }
+}
+
+fn primary_subtag(tag: &str) -> String {
+    tag.split('-')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAILABLE: [&str; 5] = ["ja-Hrkt", "en", "fr", "de", "zh-Hans"];
+
+    #[test]
+    fn test_accept_language_orders_by_quality() {
+        let preference = LanguagePreference::from_accept_language("en;q=0.5, de, fr;q=0.8");
+
+        assert_eq!(preference.best_match(&AVAILABLE), Some("de"));
+    }
+
+    #[test]
+    fn test_region_falls_back_to_primary_language() {
+        let preference = LanguagePreference::from_accept_language("fr-CH, en;q=0.9");
+
+        assert_eq!(preference.best_match(&AVAILABLE), Some("fr"));
+        assert_eq!(
+            LanguagePreference::single("zh").best_match(&AVAILABLE),
+            Some("zh-Hans")
+        );
+    }
+
+    #[test]
+    fn test_unavailable_languages_fall_back_to_english() {
+        let preference = LanguagePreference::from_accept_language("it, *;q=0.1, ko;q=0");
+
+        assert_eq!(preference.best_match(&AVAILABLE), Some("en"));
+        assert_eq!(LanguagePreference::default().best_match(&["fr"]), None);
+    }
+}
@@ -0,0 +1,55 @@
+/// A move a Pokemon learns in one version group, e.g. `thunderbolt` by
+/// `machine` in `red-blue`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LearnedMove {
+    pub name: String,
+    /// `level-up`, `machine`, `egg`, `tutor`, ...
+    pub method: String,
+    /// Only set for moves learned on level-up.
+    pub level: Option<u8>,
+    pub version_group: String,
+}
+
+/// Restricts a learnset to one version group and/or learn method.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LearnsetFilter {
+    pub version_group: Option<String>,
+    pub method: Option<String>,
+}
+
+impl LearnsetFilter {
+    pub fn matches(&self, learned: &LearnedMove) -> bool {
+        let matches = |wanted: &Option<String>, value: &str| {
+            wanted
+                .as_deref()
+                .is_none_or(|wanted| wanted.eq_ignore_ascii_case(value))
+        };
+
+        matches(&self.version_group, &learned.version_group)
+            && matches(&self.method, &learned.method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches_version_group_and_method() {
+        let learned = LearnedMove {
+            name: "thunder-shock".to_string(),
+            method: "level-up".to_string(),
+            level: Some(1),
+            version_group: "red-blue".to_string(),
+        };
+        let filter = |version_group: Option<&str>, method: Option<&str>| LearnsetFilter {
+            version_group: version_group.map(str::to_string),
+            method: method.map(str::to_string),
+        };
+
+        assert!(filter(None, None).matches(&learned));
+        assert!(filter(Some("Red-Blue"), Some("level-up")).matches(&learned));
+        assert!(!filter(Some("yellow"), None).matches(&learned));
+        assert!(!filter(None, Some("machine")).matches(&learned));
+    }
+}
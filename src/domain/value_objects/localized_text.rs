@@ -0,0 +1,77 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedText {
+    pub language: String,
+    pub text: String,
+}
+
+/// A Pokedex entry as written for one game version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlavorText {
+    pub language: String,
+    pub version: String,
+    pub text: String,
+}
+
+/// A Pokedex entry shared, word for word, by one or more game versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlavorTextGroup {
+    pub language: String,
+    pub text: String,
+    pub versions: Vec<String>,
+}
+
+/// Groups identical texts (ignoring whitespace differences) of the same
+/// language, keeping the order in which they first appear.
+pub fn group_flavor_texts(flavor_texts: &[FlavorText]) -> Vec<FlavorTextGroup> {
+    let mut groups: Vec<FlavorTextGroup> = Vec::new();
+
+    for entry in flavor_texts {
+        let text = entry.text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        match groups
+            .iter_mut()
+            .find(|group| group.language == entry.language && group.text == text)
+        {
+            Some(group) if !group.versions.contains(&entry.version) => {
+                group.versions.push(entry.version.clone())
+            }
+            Some(_) => {}
+            None => groups.push(FlavorTextGroup {
+                language: entry.language.clone(),
+                text,
+                versions: vec![entry.version.clone()],
+            }),
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flavor(language: &str, version: &str, text: &str) -> FlavorText {
+        FlavorText {
+            language: language.to_string(),
+            version: version.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_group_flavor_texts_merges_identical_texts() {
+        let groups = group_flavor_texts(&[
+            flavor("en", "red", "Electric  mouse."),
+            flavor("en", "blue", "Electric mouse."),
+            flavor("en", "yellow", "Loves berries."),
+            flavor("fr", "red", "Electric mouse."),
+        ]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].text, "Electric mouse.");
+        assert_eq!(groups[0].versions, vec!["red", "blue"]);
+        assert_eq!(groups[1].versions, vec!["yellow"]);
+        assert_eq!(groups[2].language, "fr");
+    }
+}
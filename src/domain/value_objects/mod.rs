@@ -0,0 +1,23 @@
+pub mod ability;
+pub mod base_stats;
+pub mod effect_text;
+pub mod egg_group;
+pub mod evolution_condition;
+pub mod generation;
+pub mod growth_rate;
+pub mod language;
+pub mod learned_move;
+pub mod localized_text;
+pub mod page;
+pub mod pokedex_number;
+pub mod pokemon_lookup;
+pub mod pokemon_name;
+pub mod resource_name;
+pub mod slug;
+pub mod species_filter;
+pub mod species_rarity;
+pub mod species_summary;
+pub mod sprites;
+pub mod translation;
+pub mod translator;
+pub mod variety;
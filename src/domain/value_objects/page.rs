@@ -0,0 +1,6 @@
+/// One page of a longer list, with the length of the whole list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+}
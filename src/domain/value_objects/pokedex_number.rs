@@ -0,0 +1,6 @@
+/// A species' entry number in one of PokéAPI's Pokedexes, e.g. `kanto`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokedexNumber {
+    pub pokedex: String,
+    pub entry_number: u32,
+}
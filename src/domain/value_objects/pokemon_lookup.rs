@@ -0,0 +1,61 @@
+use crate::domain::value_objects::{pokemon_name::PokemonName, resource_name::ResourceName};
+
+/// How a Pokemon is addressed in a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PokemonLookup {
+    /// A species name, or the name of one of its varieties (`giratina-origin`).
+    Name(PokemonName),
+    NationalDex(u32),
+    RegionalDex {
+        pokedex: ResourceName,
+        number: u32,
+    },
+}
+
+impl PokemonLookup {
+    /// Numeric values are dex numbers, in `pokedex` when given and in the
+    /// national dex otherwise; anything else is a name.
+    pub fn parse(value: &str, pokedex: Option<&str>) -> Result<Self, String> {
+        let value = value.trim();
+        match (value.parse::<u32>(), pokedex) {
+            (Ok(number), Some(pokedex)) => Ok(PokemonLookup::RegionalDex {
+                pokedex: pokedex.parse()?,
+                number,
+            }),
+            (Ok(number), None) => Ok(PokemonLookup::NationalDex(number)),
+            (Err(_), Some(_)) => Err(format!(
+                "pokedex requires a numeric entry number, got '{}'",
+                value
+            )),
+            (Err(_), None) => Ok(PokemonLookup::Name(value.parse()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lookup() {
+        assert_eq!(
+            PokemonLookup::parse("Giratina-Origin", None),
+            Ok(PokemonLookup::Name("giratina-origin".parse().unwrap()))
+        );
+        assert_eq!(
+            PokemonLookup::parse("25", None),
+            Ok(PokemonLookup::NationalDex(25))
+        );
+        assert_eq!(
+            PokemonLookup::parse("25", Some("Kanto")),
+            Ok(PokemonLookup::RegionalDex {
+                pokedex: "kanto".parse().unwrap(),
+                number: 25
+            })
+        );
+        assert!(PokemonLookup::parse("25", Some("../pokemon/ditto")).is_err());
+        assert!(PokemonLookup::parse("25", Some("kanto?x=1")).is_err());
+        assert!(PokemonLookup::parse("pikachu", Some("kanto")).is_err());
+        assert!(PokemonLookup::parse("pika/chu", None).is_err());
+    }
+}
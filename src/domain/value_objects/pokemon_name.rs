@@ -0,0 +1,48 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::value_objects::slug::Slug;
+
+/// A Pokemon name normalized to PokéAPI's slug format, e.g. `Mr. Mime` ->
+/// `mr-mime` or `Nidoran♀` -> `nidoran-f`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PokemonName(Slug);
+
+impl PokemonName {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for PokemonName {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Slug::parse(value, "pokemon name").map(Self)
+    }
+}
+
+impl fmt::Display for PokemonName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_name_the_pokemon() {
+        assert_eq!(
+            "Mr. Mime".parse::<PokemonName>().unwrap().as_str(),
+            "mr-mime"
+        );
+        assert!(
+            "pika/chu"
+                .parse::<PokemonName>()
+                .unwrap_err()
+                .starts_with("invalid pokemon name 'pika/chu'")
+        );
+    }
+}
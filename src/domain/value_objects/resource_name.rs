@@ -0,0 +1,29 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::value_objects::slug::Slug;
+
+/// The name of a move, ability or pokedex, normalized like a `PokemonName`,
+/// e.g. `Thunder Punch` -> `thunder-punch`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourceName(Slug);
+
+impl ResourceName {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for ResourceName {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Slug::parse(value, "name").map(Self)
+    }
+}
+
+impl fmt::Display for ResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
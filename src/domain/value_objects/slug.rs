@@ -0,0 +1,123 @@
+use std::fmt;
+
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// Longer than any PokéAPI Pokemon, variety, move or ability name.
+const MAX_SLUG_LENGTH: u64 = 64;
+
+/// A name normalized to PokéAPI's slug format, e.g. `Mr. Mime` -> `mr-mime`
+/// or `Nidoran♀` -> `nidoran-f`. The typed names wrap it so they all follow
+/// the same rules.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Validate)]
+pub struct Slug {
+    #[validate(
+        length(min = 1, max = MAX_SLUG_LENGTH, message = "must be 1 to 64 characters long"),
+        custom(
+            function = "validate_slug",
+            message = "may only contain letters, digits, spaces and - . ' :"
+        )
+    )]
+    slug: String,
+}
+
+impl Slug {
+    /// Normalizes and validates `value`; `kind` names what it is in the
+    /// error, e.g. `pokemon name`.
+    pub fn parse(value: &str, kind: &str) -> Result<Self, String> {
+        let slug = Self {
+            slug: normalize(value),
+        };
+
+        slug.validate()
+            .map_err(|errors| format!("invalid {} '{}': {}", kind, value, reasons(&errors)))?;
+
+        Ok(slug)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.slug
+    }
+}
+
+impl fmt::Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.slug)
+    }
+}
+
+/// Lowercases, spells out gender symbols, drops the punctuation PokéAPI
+/// leaves out of slugs and joins words with `-`. Anything else is kept for
+/// validation to reject.
+fn normalize(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    for c in value.trim().to_lowercase().chars() {
+        match c {
+            '♀' | '♂' => {
+                if !slug.ends_with('-') {
+                    slug.push('-');
+                }
+                slug.push(if c == '♀' { 'f' } else { 'm' });
+            }
+            'é' | 'è' | 'ê' => slug.push('e'),
+            '.' | '\'' | '’' | ':' => {}
+            c if c.is_whitespace() || c == '_' || c == '-' => {
+                if !slug.is_empty() && !slug.ends_with('-') {
+                    slug.push('-');
+                }
+            }
+            c => slug.push(c),
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    if slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::new("slug"))
+    }
+}
+
+fn reasons(errors: &ValidationErrors) -> String {
+    let reasons: Vec<String> = errors
+        .field_errors()
+        .into_values()
+        .flatten()
+        .filter_map(|error| error.message.as_ref().map(ToString::to_string))
+        .collect();
+    reasons.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_to_pokeapi_slugs() {
+        let slug = |value: &str| Slug::parse(value, "name").unwrap().to_string();
+
+        assert_eq!(slug("  Pikachu "), "pikachu");
+        assert_eq!(slug("Mr. Mime"), "mr-mime");
+        assert_eq!(slug("Nidoran♀"), "nidoran-f");
+        assert_eq!(slug("nidoran ♂"), "nidoran-m");
+        assert_eq!(slug("Farfetch’d"), "farfetchd");
+        assert_eq!(slug("Type: Null"), "type-null");
+        assert_eq!(slug("Flabébé"), "flabebe");
+        assert_eq!(slug("giratina_origin"), "giratina-origin");
+        assert_eq!(slug("25"), "25");
+    }
+
+    #[test]
+    fn test_rejects_invalid_names() {
+        assert!(Slug::parse("", "name").is_err());
+        assert!(Slug::parse(" - ", "name").is_err());
+        assert!(Slug::parse("pika/chu", "name").is_err());
+        assert!(Slug::parse("ピカチュウ", "name").is_err());
+        assert!(Slug::parse(&"a".repeat(65), "name").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::domain::value_objects::{
+    generation::Generation, species_rarity::SpeciesRarity, species_summary::SpeciesSummary,
+};
+
+/// Criteria for listing species. Unset criteria match anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpeciesFilter {
+    pub generation: Option<Generation>,
+    pub habitat: Option<String>,
+    pub color: Option<String>,
+    pub is_legendary: Option<bool>,
+    pub is_mythical: Option<bool>,
+}
+
+impl SpeciesFilter {
+    /// Checks the criteria a `SpeciesSummary` carries.
+    pub fn matches_summary(&self, species: &SpeciesSummary) -> bool {
+        self.generation
+            .is_none_or(|generation| species.generation == Some(generation))
+            && self
+                .habitat
+                .as_deref()
+                .is_none_or(|habitat| species.habitat.as_deref() == Some(habitat))
+            && self
+                .color
+                .as_deref()
+                .is_none_or(|color| species.color.as_deref() == Some(color))
+    }
+
+    /// Whether the legendary or mythical criteria are set, which need each
+    /// candidate species' rarity.
+    pub fn needs_rarity(&self) -> bool {
+        self.is_legendary.is_some() || self.is_mythical.is_some()
+    }
+
+    pub fn matches_rarity(&self, rarity: SpeciesRarity) -> bool {
+        self.is_legendary
+            .is_none_or(|legendary| legendary == rarity.is_legendary)
+            && self
+                .is_mythical
+                .is_none_or(|mythical| mythical == rarity.is_mythical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches_summary_and_rarity() {
+        let pikachu = SpeciesSummary {
+            id: 25,
+            name: "pikachu".to_string(),
+            generation: Generation::new(1),
+            habitat: Some("forest".to_string()),
+            color: Some("yellow".to_string()),
+        };
+
+        assert!(SpeciesFilter::default().matches_summary(&pikachu));
+
+        let filter = SpeciesFilter {
+            generation: Generation::new(1),
+            color: Some("yellow".to_string()),
+            is_legendary: Some(false),
+            ..SpeciesFilter::default()
+        };
+        assert!(filter.matches_summary(&pikachu));
+        assert!(filter.needs_rarity());
+        assert!(filter.matches_rarity(SpeciesRarity::default()));
+        assert!(!filter.matches_rarity(SpeciesRarity {
+            is_legendary: true,
+            is_mythical: false,
+        }));
+
+        let filter = SpeciesFilter {
+            habitat: Some("cave".to_string()),
+            ..SpeciesFilter::default()
+        };
+        assert!(!filter.matches_summary(&pikachu));
+        assert!(!filter.needs_rarity());
+    }
+}
@@ -0,0 +1,7 @@
+/// Whether a species is legendary or mythical. PokéAPI only has these per
+/// species, so they are resolved lazily rather than listed in bulk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeciesRarity {
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+}
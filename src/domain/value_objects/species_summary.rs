@@ -0,0 +1,12 @@
+use crate::domain::value_objects::generation::Generation;
+
+/// The attributes of a species that PokéAPI can list in bulk, without a
+/// lookup per species.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeciesSummary {
+    pub id: u32,
+    pub name: String,
+    pub generation: Option<Generation>,
+    pub habitat: Option<String>,
+    pub color: Option<String>,
+}
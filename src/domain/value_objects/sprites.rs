@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sprites {
+    pub front_default: Option<String>,
+    pub front_shiny: Option<String>,
+}
@@ -0,0 +1,35 @@
+use crate::domain::value_objects::translator::Translator;
+
+/// A translated text as returned by the translation repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    pub text: String,
+    pub from_cache: bool,
+}
+
+/// Why a Pokemon kept its original description instead of a translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackReason {
+    RateLimited,
+    ServiceUnavailable,
+    TranslationFailed,
+}
+
+impl FallbackReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FallbackReason::RateLimited => "rate_limited",
+            FallbackReason::ServiceUnavailable => "service_unavailable",
+            FallbackReason::TranslationFailed => "translation_failed",
+        }
+    }
+}
+
+/// What happened when a Pokemon's description was translated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationDetails {
+    pub original_description: String,
+    pub translator: Translator,
+    pub from_cache: bool,
+    pub fallback_reason: Option<FallbackReason>,
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A FunTranslations dialect. `as_str` is the endpoint name used by the API
+/// (`/translate/{name}.json`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Translator {
+    Shakespeare,
+    Yoda,
+    Pirate,
+    Minion,
+    Klingon,
+    Sith,
+    Valyrian,
+    Dothraki,
+    Gungan,
+    Vulcan,
+    Mandalorian,
+    Huttese,
+    OldEnglish,
+    PigLatin,
+}
+
+impl Translator {
+    pub const ALL: [Translator; 14] = [
+        Translator::Shakespeare,
+        Translator::Yoda,
+        Translator::Pirate,
+        Translator::Minion,
+        Translator::Klingon,
+        Translator::Sith,
+        Translator::Valyrian,
+        Translator::Dothraki,
+        Translator::Gungan,
+        Translator::Vulcan,
+        Translator::Mandalorian,
+        Translator::Huttese,
+        Translator::OldEnglish,
+        Translator::PigLatin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Translator::Shakespeare => "shakespeare",
+            Translator::Yoda => "yoda",
+            Translator::Pirate => "pirate",
+            Translator::Minion => "minion",
+            Translator::Klingon => "klingon",
+            Translator::Sith => "sith",
+            Translator::Valyrian => "valyrian",
+            Translator::Dothraki => "dothraki",
+            Translator::Gungan => "gungan",
+            Translator::Vulcan => "vulcan",
+            Translator::Mandalorian => "mandalorian",
+            Translator::Huttese => "huttese",
+            Translator::OldEnglish => "oldenglish",
+            Translator::PigLatin => "pig-latin",
+        }
+    }
+}
+
+impl fmt::Display for Translator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Translator {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_lowercase();
+
+        Translator::ALL
+            .into_iter()
+            .find(|translator| translator.as_str() == value)
+            .ok_or_else(|| format!("unknown translator: {}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translator_round_trips_through_its_name() {
+        for translator in Translator::ALL {
+            assert_eq!(translator.as_str().parse::<Translator>(), Ok(translator));
+        }
+    }
+
+    #[test]
+    fn test_translator_parse_is_case_insensitive() {
+        assert_eq!(" Pirate ".parse::<Translator>(), Ok(Translator::Pirate));
+        assert!("elvish".parse::<Translator>().is_err());
+    }
+}
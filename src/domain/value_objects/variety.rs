@@ -0,0 +1,6 @@
+/// A Pokemon belonging to a species, e.g. a regional or mega form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variety {
+    pub name: String,
+    pub is_default: bool,
+}
@@ -1,17 +1,22 @@
 use crate::{
     adapters::{
-        http::{app_state::AppState, routes::api_routes::api_routes},
+        http::{
+            app_state::AppState,
+            middleware::{assign_request_id, track_requests},
+            routes::api_routes::api_routes,
+        },
         openapi::ApiDoc,
     },
+    application::request_id::RequestId,
     infra::setup::init_tracing,
 };
-use axum::{Router, http};
+use axum::{Router, http, middleware};
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 pub fn create_app(app_state: AppState) -> Router {
-    init_tracing();
+    init_tracing(app_state.config.log_format);
 
     let mut router = Router::new();
 
@@ -20,14 +25,25 @@ pub fn create_app(app_state: AppState) -> Router {
             .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()));
     }
 
-    router.merge(api_routes()).with_state(app_state).layer(
-        TraceLayer::new_for_http().make_span_with(|request: &http::Request<_>| {
-            tracing::info_span!(
-                "http-request",
-                method = %request.method(),
-                uri = %request.uri(),
-                version = ?request.version(),
-            )
-        }),
-    )
+    let metrics = app_state.metrics.clone();
+
+    router
+        .merge(api_routes())
+        .with_state(app_state)
+        .layer(middleware::from_fn_with_state(metrics, track_requests))
+        .layer(
+            TraceLayer::new_for_http().make_span_with(|request: &http::Request<_>| {
+                tracing::info_span!(
+                    "http-request",
+                    method = %request.method(),
+                    uri = %request.uri(),
+                    version = ?request.version(),
+                    request_id = request
+                        .extensions()
+                        .get::<RequestId>()
+                        .map_or("", |id| id.as_str()),
+                )
+            }),
+        )
+        .layer(middleware::from_fn(assign_request_id))
 }
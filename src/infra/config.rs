@@ -1,11 +1,124 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranslationCacheMode {
+    Memory,
+    Persistent,
+}
+
+impl FromStr for TranslationCacheMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "memory" => Ok(Self::Memory),
+            "persistent" => Ok(Self::Persistent),
+            other => Err(format!("unknown translation cache mode: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown log format: {}", other)),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AppConfig {
     pub server_port: u16,
     pub enable_swagger: bool,
+    pub log_format: LogFormat,
+    pub pokeapi_base_url: String,
+    pub pokeapi_timeout_secs: u64,
+    pub funtranslations_base_url: String,
+    pub funtranslations_timeout_secs: u64,
+    pub funtranslations_circuit_failure_threshold: u32,
+    pub funtranslations_circuit_open_secs: u64,
+    pub upstream_connect_timeout_secs: u64,
+    pub upstream_read_timeout_secs: u64,
+    pub upstream_max_retries: u32,
+    pub upstream_retry_base_delay_ms: u64,
+    pub upstream_retry_max_delay_ms: u64,
+    pub pokemon_cache_ttl_secs: u64,
+    pub pokemon_cache_capacity: u64,
+    pub pokemon_not_found_cache_ttl_secs: u64,
+    pub evolution_cache_ttl_secs: u64,
+    pub evolution_cache_capacity: u64,
+    pub move_cache_ttl_secs: u64,
+    pub move_cache_capacity: u64,
+    pub ability_cache_ttl_secs: u64,
+    pub ability_cache_capacity: u64,
+    pub species_catalog_ttl_secs: u64,
+    pub species_index_ttl_secs: u64,
+    pub type_chart_ttl_secs: u64,
+    pub batch_max_size: usize,
+    pub batch_concurrency: usize,
+    pub translation_cache_soft_ttl_secs: u64,
+    pub translation_cache_hard_ttl_secs: u64,
+    pub translation_cache_capacity: u64,
+    pub translation_cache_mode: TranslationCacheMode,
+    pub translation_cache_path: String,
+    pub translation_policy_file: Option<String>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            server_port: 8080,
+            enable_swagger: true,
+            log_format: LogFormat::Pretty,
+            pokeapi_base_url: "https://pokeapi.co/api/v2".to_string(),
+            pokeapi_timeout_secs: 10,
+            funtranslations_base_url: "https://api.funtranslations.com".to_string(),
+            funtranslations_timeout_secs: 10,
+            funtranslations_circuit_failure_threshold: 3,
+            funtranslations_circuit_open_secs: 60,
+            upstream_connect_timeout_secs: 3,
+            upstream_read_timeout_secs: 10,
+            upstream_max_retries: 2,
+            upstream_retry_base_delay_ms: 200,
+            upstream_retry_max_delay_ms: 5000,
+            pokemon_cache_ttl_secs: 3600,
+            pokemon_cache_capacity: 1000,
+            pokemon_not_found_cache_ttl_secs: 60,
+            evolution_cache_ttl_secs: 86_400,
+            evolution_cache_capacity: 500,
+            move_cache_ttl_secs: 86_400,
+            move_cache_capacity: 1_000,
+            ability_cache_ttl_secs: 86_400,
+            ability_cache_capacity: 1_000,
+            species_catalog_ttl_secs: 86_400,
+            species_index_ttl_secs: 86_400,
+            type_chart_ttl_secs: 86_400,
+            batch_max_size: 20,
+            batch_concurrency: 4,
+            translation_cache_soft_ttl_secs: 86_400,
+            translation_cache_hard_ttl_secs: 2_592_000,
+            translation_cache_capacity: 1000,
+            translation_cache_mode: TranslationCacheMode::Memory,
+            translation_cache_path: "translation_cache.jsonl".to_string(),
+            translation_policy_file: None,
+        }
+    }
 }
 
 impl AppConfig {
     pub fn from_env() -> Self {
+        let defaults = Self::default();
+
         let server_port = std::env::var("SERVER_PORT").expect("SERVER_PORT must be set");
 
         let enable_swagger = std::env::var("ENABLE_SWAGGER")
@@ -16,6 +129,113 @@ impl AppConfig {
         Self {
             server_port: server_port.parse().expect("SERVER_PORT must be a number"),
             enable_swagger,
+            log_format: env_or("LOG_FORMAT", defaults.log_format),
+            pokeapi_base_url: env_or("POKEAPI_BASE_URL", defaults.pokeapi_base_url),
+            pokeapi_timeout_secs: env_or("POKEAPI_TIMEOUT_SECS", defaults.pokeapi_timeout_secs),
+            funtranslations_base_url: env_or(
+                "FUNTRANSLATIONS_BASE_URL",
+                defaults.funtranslations_base_url,
+            ),
+            funtranslations_timeout_secs: env_or(
+                "FUNTRANSLATIONS_TIMEOUT_SECS",
+                defaults.funtranslations_timeout_secs,
+            ),
+            funtranslations_circuit_failure_threshold: env_or(
+                "FUNTRANSLATIONS_CIRCUIT_FAILURE_THRESHOLD",
+                defaults.funtranslations_circuit_failure_threshold,
+            ),
+            funtranslations_circuit_open_secs: env_or(
+                "FUNTRANSLATIONS_CIRCUIT_OPEN_SECS",
+                defaults.funtranslations_circuit_open_secs,
+            ),
+            upstream_connect_timeout_secs: env_or(
+                "UPSTREAM_CONNECT_TIMEOUT_SECS",
+                defaults.upstream_connect_timeout_secs,
+            ),
+            upstream_read_timeout_secs: env_or(
+                "UPSTREAM_READ_TIMEOUT_SECS",
+                defaults.upstream_read_timeout_secs,
+            ),
+            upstream_max_retries: env_or("UPSTREAM_MAX_RETRIES", defaults.upstream_max_retries),
+            upstream_retry_base_delay_ms: env_or(
+                "UPSTREAM_RETRY_BASE_DELAY_MS",
+                defaults.upstream_retry_base_delay_ms,
+            ),
+            upstream_retry_max_delay_ms: env_or(
+                "UPSTREAM_RETRY_MAX_DELAY_MS",
+                defaults.upstream_retry_max_delay_ms,
+            ),
+            pokemon_cache_ttl_secs: env_or(
+                "POKEMON_CACHE_TTL_SECS",
+                defaults.pokemon_cache_ttl_secs,
+            ),
+            pokemon_cache_capacity: env_or(
+                "POKEMON_CACHE_CAPACITY",
+                defaults.pokemon_cache_capacity,
+            ),
+            pokemon_not_found_cache_ttl_secs: env_or(
+                "POKEMON_NOT_FOUND_CACHE_TTL_SECS",
+                defaults.pokemon_not_found_cache_ttl_secs,
+            ),
+            evolution_cache_ttl_secs: env_or(
+                "EVOLUTION_CACHE_TTL_SECS",
+                defaults.evolution_cache_ttl_secs,
+            ),
+            evolution_cache_capacity: env_or(
+                "EVOLUTION_CACHE_CAPACITY",
+                defaults.evolution_cache_capacity,
+            ),
+            move_cache_ttl_secs: env_or("MOVE_CACHE_TTL_SECS", defaults.move_cache_ttl_secs),
+            move_cache_capacity: env_or("MOVE_CACHE_CAPACITY", defaults.move_cache_capacity),
+            ability_cache_ttl_secs: env_or(
+                "ABILITY_CACHE_TTL_SECS",
+                defaults.ability_cache_ttl_secs,
+            ),
+            ability_cache_capacity: env_or(
+                "ABILITY_CACHE_CAPACITY",
+                defaults.ability_cache_capacity,
+            ),
+            species_catalog_ttl_secs: env_or(
+                "SPECIES_CATALOG_TTL_SECS",
+                defaults.species_catalog_ttl_secs,
+            ),
+            species_index_ttl_secs: env_or(
+                "SPECIES_INDEX_TTL_SECS",
+                defaults.species_index_ttl_secs,
+            ),
+            type_chart_ttl_secs: env_or("TYPE_CHART_TTL_SECS", defaults.type_chart_ttl_secs),
+            batch_max_size: env_or("BATCH_MAX_SIZE", defaults.batch_max_size),
+            batch_concurrency: env_or("BATCH_CONCURRENCY", defaults.batch_concurrency),
+            translation_cache_soft_ttl_secs: env_or(
+                "TRANSLATION_CACHE_SOFT_TTL_SECS",
+                defaults.translation_cache_soft_ttl_secs,
+            ),
+            translation_cache_hard_ttl_secs: env_or(
+                "TRANSLATION_CACHE_HARD_TTL_SECS",
+                defaults.translation_cache_hard_ttl_secs,
+            ),
+            translation_cache_capacity: env_or(
+                "TRANSLATION_CACHE_CAPACITY",
+                defaults.translation_cache_capacity,
+            ),
+            translation_cache_mode: env_or(
+                "TRANSLATION_CACHE_MODE",
+                defaults.translation_cache_mode,
+            ),
+            translation_cache_path: env_or(
+                "TRANSLATION_CACHE_PATH",
+                defaults.translation_cache_path,
+            ),
+            translation_policy_file: std::env::var("TRANSLATION_POLICY_FILE").ok(),
         }
     }
 }
+
+fn env_or<T: FromStr>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} has an invalid value: {}", key, value)),
+        Err(_) => default,
+    }
+}
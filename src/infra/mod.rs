@@ -1,3 +1,4 @@
 pub mod app;
 pub mod config;
 pub mod setup;
+pub mod translation_policy;
@@ -1,46 +1,304 @@
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
-use tracing_subscriber::{EnvFilter, fmt};
+use tracing_subscriber::{EnvFilter, Layer, fmt};
 
+use crate::adapters::cache::file_translation_cache::FileTranslationCache;
+use crate::adapters::cache::translation_cache::{CachedTranslation, TranslationCache};
+use crate::adapters::cache::typed_cache::TypedCache;
+use crate::adapters::clients::circuit_breaker::CircuitBreaker;
 use crate::adapters::clients::funtranslations::FunsTranslationsApiClient;
 use crate::adapters::clients::pokeapi::PokeApiClient;
+use crate::adapters::clients::upstream::{RetryPolicy, UpstreamHttpClient};
 use crate::adapters::http::app_state::AppState;
+use crate::adapters::metrics::Metrics;
+use crate::adapters::repositories::ability_repository_impl::AbilityRepositoryImpl;
+use crate::adapters::repositories::cached_pokemon_repository::CachedPokemonRepository;
+use crate::adapters::repositories::evolution_repository_impl::EvolutionRepositoryImpl;
+use crate::adapters::repositories::move_repository_impl::MoveRepositoryImpl;
 use crate::adapters::repositories::pokemon_repository_impl::PokemonRepositoryImpl;
+use crate::adapters::repositories::species_index_repository_impl::SpeciesIndexRepositoryImpl;
 use crate::adapters::repositories::translation_repository_impl::TranslationRepositoryImpl;
+use crate::adapters::repositories::type_repository_impl::TypeRepositoryImpl;
+use crate::application::translation_policy::RuleBasedTranslationPolicy;
+use crate::application::use_cases::ability_use_cases::AbilityUseCases;
+use crate::application::use_cases::evolution_use_cases::EvolutionUseCases;
+use crate::application::use_cases::move_use_cases::MoveUseCases;
 use crate::application::use_cases::pokemon_use_cases::PokemonUseCases;
-use crate::infra::config::AppConfig;
+use crate::application::use_cases::type_use_cases::TypeUseCases;
+use crate::infra::config::{AppConfig, LogFormat, TranslationCacheMode};
+use crate::infra::translation_policy::load_translation_policy;
 
-pub async fn init_app_state() -> Result<AppState, Box<dyn std::error::Error>> {
-    let config = AppConfig::from_env();
+/// Comfortably above the roughly 1,000 species PokéAPI knows, so the rarity
+/// of every species fits.
+const SPECIES_RARITY_CAPACITY: u64 = 4_096;
 
-    let pokeapi_client = Arc::new(PokeApiClient::new());
-    let fun_translations_client = Arc::new(FunsTranslationsApiClient::new());
+pub async fn init_app_state(config: AppConfig) -> Result<AppState, Box<dyn std::error::Error>> {
+    let metrics = Arc::new(Metrics::new());
+    let upstream_http = Arc::new(UpstreamHttpClient::new(
+        Duration::from_secs(config.upstream_connect_timeout_secs),
+        Duration::from_secs(config.upstream_read_timeout_secs),
+        RetryPolicy {
+            max_retries: config.upstream_max_retries,
+            base_delay: Duration::from_millis(config.upstream_retry_base_delay_ms),
+            max_delay: Duration::from_millis(config.upstream_retry_max_delay_ms),
+        },
+    ));
 
-    let pokemon_repository = Arc::new(PokemonRepositoryImpl::new(pokeapi_client));
-    let translation_repository = Arc::new(TranslationRepositoryImpl::new(fun_translations_client));
+    let pokeapi_client = Arc::new(
+        PokeApiClient::new(
+            upstream_http.clone(),
+            &config.pokeapi_base_url,
+            Duration::from_secs(config.pokeapi_timeout_secs),
+        )
+        .with_metrics(metrics.clone()),
+    );
+    let fun_translations_circuit = Arc::new(CircuitBreaker::new(
+        config.funtranslations_circuit_failure_threshold,
+        Duration::from_secs(config.funtranslations_circuit_open_secs),
+    ));
+    let fun_translations_client = Arc::new(
+        FunsTranslationsApiClient::new(
+            upstream_http,
+            fun_translations_circuit.clone(),
+            &config.funtranslations_base_url,
+            Duration::from_secs(config.funtranslations_timeout_secs),
+        )
+        .with_metrics(metrics.clone()),
+    );
 
-    let pokemon_use_cases =
-        PokemonUseCases::new(pokemon_repository.clone(), translation_repository.clone());
+    let pokemon_repository = Arc::new(CachedPokemonRepository::new(
+        Arc::new(PokemonRepositoryImpl::new(pokeapi_client.clone())),
+        TypedCache::new(
+            config.pokemon_cache_capacity,
+            Duration::from_secs(config.pokemon_cache_ttl_secs),
+            Some(metrics.cache("pokemon")),
+        ),
+        TypedCache::new(
+            config.pokemon_cache_capacity,
+            Duration::from_secs(config.pokemon_not_found_cache_ttl_secs),
+            Some(metrics.cache("pokemon_not_found")),
+        ),
+        TypedCache::new(
+            1,
+            Duration::from_secs(config.species_catalog_ttl_secs),
+            Some(metrics.cache("species_catalog")),
+        ),
+        TypedCache::new(
+            SPECIES_RARITY_CAPACITY,
+            Duration::from_secs(config.species_catalog_ttl_secs),
+            Some(metrics.cache("species_rarity")),
+        ),
+    ));
+    let species_index_repository = Arc::new(SpeciesIndexRepositoryImpl::new(
+        pokemon_repository.clone(),
+        TypedCache::new(
+            1,
+            Duration::from_secs(config.species_index_ttl_secs),
+            Some(metrics.cache("species_index")),
+        ),
+    ));
+    let type_repository = Arc::new(TypeRepositoryImpl::new(
+        pokeapi_client.clone(),
+        TypedCache::new(
+            1,
+            Duration::from_secs(config.type_chart_ttl_secs),
+            Some(metrics.cache("type_chart")),
+        ),
+    ));
+    let ability_repository = Arc::new(AbilityRepositoryImpl::new(
+        pokeapi_client.clone(),
+        TypedCache::new(
+            config.ability_cache_capacity,
+            Duration::from_secs(config.ability_cache_ttl_secs),
+            Some(metrics.cache("abilities")),
+        ),
+    ));
+    let move_repository = Arc::new(MoveRepositoryImpl::new(
+        pokeapi_client.clone(),
+        TypedCache::new(
+            config.move_cache_capacity,
+            Duration::from_secs(config.move_cache_ttl_secs),
+            Some(metrics.cache("moves")),
+        ),
+    ));
+    let evolution_repository = Arc::new(EvolutionRepositoryImpl::new(
+        pokeapi_client,
+        TypedCache::new(
+            config.evolution_cache_capacity,
+            Duration::from_secs(config.evolution_cache_ttl_secs),
+            Some(metrics.cache("evolution_chains")),
+        ),
+    ));
+    let translation_cache_ttl = Duration::from_secs(config.translation_cache_hard_ttl_secs);
+    let translation_cache: Arc<dyn TranslationCache> = match config.translation_cache_mode {
+        TranslationCacheMode::Memory => Arc::new(TypedCache::<String, CachedTranslation>::new(
+            config.translation_cache_capacity,
+            translation_cache_ttl,
+            Some(metrics.cache("translations")),
+        )),
+        TranslationCacheMode::Persistent => Arc::new(
+            FileTranslationCache::open(
+                &config.translation_cache_path,
+                config.translation_cache_capacity,
+                translation_cache_ttl,
+                Some(metrics.cache("translations")),
+            )
+            .await?,
+        ),
+    };
+    let translation_repository = Arc::new(TranslationRepositoryImpl::new(
+        fun_translations_client,
+        translation_cache,
+        Duration::from_secs(config.translation_cache_soft_ttl_secs),
+    ));
+
+    let translation_policy = match &config.translation_policy_file {
+        Some(path) => load_translation_policy(path)?,
+        None => RuleBasedTranslationPolicy::default(),
+    };
+
+    let evolution_use_cases =
+        EvolutionUseCases::new(pokemon_repository.clone(), evolution_repository);
+    let ability_use_cases = AbilityUseCases::new(ability_repository);
+    let move_use_cases = MoveUseCases::new(pokemon_repository.clone(), move_repository);
+    let type_use_cases = TypeUseCases::new(pokemon_repository.clone(), type_repository);
+    let pokemon_use_cases = PokemonUseCases::new(
+        pokemon_repository.clone(),
+        translation_repository.clone(),
+        Arc::new(translation_policy),
+    )
+    .with_species_index(species_index_repository)
+    .with_batch_concurrency(config.batch_concurrency);
 
     Ok(AppState {
         config,
         pokemon_use_cases: Arc::new(pokemon_use_cases),
+        evolution_use_cases: Arc::new(evolution_use_cases),
+        type_use_cases: Arc::new(type_use_cases),
+        ability_use_cases: Arc::new(ability_use_cases),
+        move_use_cases: Arc::new(move_use_cases),
+        fun_translations_circuit,
+        metrics,
     })
 }
 
-pub fn init_tracing() {
+/// Builds the species catalog and then the species index in the background,
+/// and rebuilds each every half TTL so requests never wait on PokéAPI for
+/// them. A failed rebuild keeps the current one until the next attempt.
+pub fn spawn_species_refresh(state: &AppState) -> JoinHandle<()> {
+    let pokemon_use_cases = state.pokemon_use_cases.clone();
+    let catalog_period = refresh_period(state.config.species_catalog_ttl_secs);
+    let index_period = refresh_period(state.config.species_index_ttl_secs);
+
+    tokio::spawn(async move {
+        let mut catalog_ticks = tokio::time::interval(catalog_period);
+        let mut index_ticks = tokio::time::interval(index_period);
+        loop {
+            // Both tick right away; the index is built from the catalog.
+            tokio::select! {
+                biased;
+                _ = catalog_ticks.tick() => {
+                    if let Err(e) = pokemon_use_cases.refresh_species_catalog().await {
+                        tracing::warn!(error = %e, "Failed to refresh species catalog");
+                    }
+                }
+                _ = index_ticks.tick() => {
+                    if let Err(e) = pokemon_use_cases.refresh_species_index().await {
+                        tracing::warn!(error = %e, "Failed to refresh species index");
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn refresh_period(ttl_secs: u64) -> Duration {
+    Duration::from_secs(ttl_secs / 2).max(Duration::from_secs(1))
+}
+
+pub fn init_tracing(format: LogFormat) {
     let filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| "axum_trainer=debug,tower_http=debug".into());
+        .unwrap_or_else(|_| "simple_pokedex=info,tower_http=debug".into());
 
-    let console_layer = fmt::layer()
-        .with_target(false) // don’t show target (module path)
-        .with_level(true) // show log level
-        .pretty(); // human-friendly, with colors
+    let (pretty_layer, json_layer) = match format {
+        LogFormat::Pretty => (
+            Some(
+                fmt::layer()
+                    .with_target(false) // don’t show target (module path)
+                    .with_level(true) // show log level
+                    .pretty(), // human-friendly, with colors
+            ),
+            None,
+        ),
+        LogFormat::Json => (None, Some(json_layer(std::io::stdout))),
+    };
 
     tracing_subscriber::registry()
         .with(filter)
-        .with(console_layer)
+        .with(pretty_layer)
+        .with(json_layer)
         .try_init()
         .ok();
 }
+
+/// One JSON object per event, written to `writer`.
+fn json_layer<S, W>(writer: W) -> impl Layer<S>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    fmt::layer()
+        .json()
+        .flatten_event(true) // event fields at the top level
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_writer(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_json_logs_carry_structured_fields() {
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::registry().with(json_layer(move || writer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request", request_id = "abc-123");
+            let _entered = span.enter();
+            tracing::info!(cache = "pokemon", cache_status = "hit", "Cache lookup");
+        });
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let line: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["message"], "Cache lookup");
+        assert_eq!(line["cache"], "pokemon");
+        assert_eq!(line["cache_status"], "hit");
+        assert_eq!(line["span"]["request_id"], "abc-123");
+    }
+}
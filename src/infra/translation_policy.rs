@@ -0,0 +1,124 @@
+use serde::Deserialize;
+
+use crate::application::translation_policy::{
+    RuleBasedTranslationPolicy, RuleConditions, TranslationRule,
+};
+use crate::domain::value_objects::{generation::Generation, translator::Translator};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TranslationPolicyFile {
+    default: String,
+    #[serde(default)]
+    rules: Vec<TranslationRuleEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TranslationRuleEntry {
+    #[serde(default)]
+    when: RuleConditionsEntry,
+    translator: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConditionsEntry {
+    habitat: Option<String>,
+    legendary: Option<bool>,
+    mythical: Option<bool>,
+    generation: Option<String>,
+    #[serde(rename = "type")]
+    pokemon_type: Option<String>,
+}
+
+pub fn load_translation_policy(
+    path: &str,
+) -> Result<RuleBasedTranslationPolicy, Box<dyn std::error::Error>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read translation policy {}: {}", path, e))?;
+
+    parse_translation_policy(&contents)
+        .map_err(|e| format!("Invalid translation policy {}: {}", path, e).into())
+}
+
+fn parse_translation_policy(
+    contents: &str,
+) -> Result<RuleBasedTranslationPolicy, Box<dyn std::error::Error>> {
+    let file: TranslationPolicyFile = serde_json::from_str(contents)?;
+
+    let rules = file
+        .rules
+        .into_iter()
+        .map(|rule| {
+            Ok(TranslationRule {
+                conditions: RuleConditions {
+                    habitat: rule.when.habitat,
+                    is_legendary: rule.when.legendary,
+                    is_mythical: rule.when.mythical,
+                    generation: rule
+                        .when
+                        .generation
+                        .as_deref()
+                        .map(str::parse::<Generation>)
+                        .transpose()?,
+                    pokemon_type: rule.when.pokemon_type,
+                },
+                translator: rule.translator.parse::<Translator>()?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(RuleBasedTranslationPolicy::new(
+        rules,
+        file.default.parse::<Translator>()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_translation_policy() {
+        let policy = parse_translation_policy(
+            r#"{
+                "default": "shakespeare",
+                "rules": [
+                    { "when": { "habitat": "cave" }, "translator": "yoda" },
+                    { "when": { "legendary": true }, "translator": "yoda" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(policy, RuleBasedTranslationPolicy::default());
+    }
+
+    #[test]
+    fn test_parse_translation_policy_rejects_unknown_translator() {
+        let result = parse_translation_policy(
+            r#"{ "default": "shakespeare", "rules": [{ "translator": "elvish" }] }"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_translation_policy_rejects_unknown_condition() {
+        let result = parse_translation_policy(
+            r#"{ "default": "yoda", "rules": [{ "when": { "colour": "red" }, "translator": "yoda" }] }"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_translation_policy_rejects_unknown_generation() {
+        let result = parse_translation_policy(
+            r#"{ "default": "yoda", "rules": [{ "when": { "generation": "gen-1" }, "translator": "yoda" }] }"#,
+        );
+
+        assert!(result.is_err());
+    }
+}
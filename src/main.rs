@@ -1,13 +1,18 @@
-use simple_pokedex::infra::{app::create_app, setup::init_app_state};
+use simple_pokedex::infra::{
+    app::create_app,
+    config::AppConfig,
+    setup::{init_app_state, spawn_species_refresh},
+};
 use tracing::info;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenvy::dotenv().ok();
 
-    let app_state = init_app_state().await?;
+    let app_state = init_app_state(AppConfig::from_env()).await?;
 
     let app = create_app(app_state.clone());
+    spawn_species_refresh(&app_state);
 
     let listener =
         tokio::net::TcpListener::bind(format!("0.0.0.0:{}", app_state.config.server_port))
@@ -0,0 +1,1405 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::extract::{Path, Request as StubRequest};
+use axum::http::{HeaderMap, Request, StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use simple_pokedex::infra::{app::create_app, config::AppConfig, setup::init_app_state};
+use tower::ServiceExt;
+
+fn species_fixture(name: &str, habitat: &str, is_legendary: bool) -> Value {
+    let resource = |name: &str| json!({ "name": name, "url": "" });
+
+    json!({
+        "id": 1,
+        "name": name,
+        "order": 1,
+        "gender_rate": 4,
+        "capture_rate": 45,
+        "base_happiness": 50,
+        "is_baby": false,
+        "is_legendary": is_legendary,
+        "is_mythical": false,
+        "hatch_counter": 20,
+        "has_gender_differences": false,
+        "forms_switchable": false,
+        "growth_rate": resource("medium"),
+        "pokedex_numbers": [
+            { "entry_number": 25, "pokedex": resource("national") },
+            { "entry_number": 22, "pokedex": resource("original-johto") },
+        ],
+        "egg_groups": [resource("ground"), resource("fairy")],
+        "color": resource("yellow"),
+        "shape": resource("quadruped"),
+        "evolves_from_species": null,
+        "evolution_chain": { "url": "https://pokeapi.co/api/v2/evolution-chain/10/" },
+        "habitat": resource(habitat),
+        "generation": resource("generation-i"),
+        "names": [
+            { "name": "Stub", "language": resource("en") },
+            { "name": "Bouchon", "language": resource("fr") },
+        ],
+        "flavor_text_entries": [
+            {
+                "flavor_text": "A stub description.",
+                "language": resource("en"),
+                "version": resource("red"),
+            },
+            {
+                "flavor_text": "Une description\nbouchon.",
+                "language": resource("fr"),
+                "version": resource("x"),
+            },
+            {
+                "flavor_text": "A stub\u{c}description.",
+                "language": resource("en"),
+                "version": resource("blue"),
+            },
+            {
+                "flavor_text": "A yellow description.",
+                "language": resource("en"),
+                "version": resource("yellow"),
+            },
+        ],
+        "form_descriptions": [],
+        "genera": [
+            { "genus": "Stub Pokémon", "language": resource("en") },
+            { "genus": "Pokémon Bouchon", "language": resource("fr") },
+        ],
+        "varieties": [{ "is_default": true, "pokemon": resource(name) }],
+    })
+}
+
+fn pokemon_fixture(name: &str, species: &str) -> Value {
+    let resource = |name: &str| json!({ "name": name, "url": "" });
+    let stat = |name: &str, base_stat: u16| json!({ "base_stat": base_stat, "effort": 0, "stat": resource(name) });
+    let learned = |level: u8, method: &str, version_group: &str| {
+        json!({
+            "level_learned_at": level,
+            "move_learn_method": resource(method),
+            "version_group": resource(version_group),
+        })
+    };
+
+    json!({
+        "id": 25,
+        "name": name,
+        "species": resource(species),
+        "height": 4,
+        "weight": 60,
+        "types": [{ "slot": 1, "type": resource("electric") }],
+        "stats": [
+            stat("hp", 35),
+            stat("attack", 55),
+            stat("defense", 40),
+            stat("special-attack", 50),
+            stat("special-defense", 50),
+            stat("speed", 90),
+        ],
+        "abilities": [
+            { "ability": resource("static"), "is_hidden": false, "slot": 1 },
+            { "ability": resource("lightning-rod"), "is_hidden": true, "slot": 3 },
+        ],
+        "moves": [
+            {
+                "move": resource("thunderbolt"),
+                "version_group_details": [learned(26, "level-up", "red-blue"), learned(0, "machine", "red-blue")],
+            },
+            {
+                "move": resource("thunder-shock"),
+                "version_group_details": [learned(1, "level-up", "red-blue"), learned(1, "level-up", "yellow")],
+            },
+        ],
+        "sprites": { "front_default": "https://img/pikachu.png", "front_shiny": null },
+    })
+}
+
+fn ability_fixture() -> Value {
+    let resource = |name: &str| json!({ "name": name, "url": "" });
+
+    json!({
+        "name": "static",
+        "effect_entries": [
+            { "effect": "Paralyzes on contact.", "short_effect": "May paralyze.", "language": resource("en") },
+            { "effect": "Paralysiert bei Kontakt.", "short_effect": "Kann paralysieren.", "language": resource("de") },
+        ],
+        "pokemon": [
+            { "is_hidden": false, "slot": 1, "pokemon": resource("pikachu") },
+            { "is_hidden": true, "slot": 3, "pokemon": resource("electrode") },
+        ],
+    })
+}
+
+fn move_fixture() -> Value {
+    let resource = |name: &str| json!({ "name": name, "url": "" });
+
+    json!({
+        "name": "thunderbolt",
+        "power": 90,
+        "accuracy": 100,
+        "pp": 15,
+        "priority": 0,
+        "effect_chance": 10,
+        "type": resource("electric"),
+        "damage_class": resource("special"),
+        "effect_entries": [{
+            "effect": "Has a $effect_chance% chance to paralyze the target.",
+            "short_effect": "Has a $effect_chance% chance to paralyze.",
+            "language": resource("en"),
+        }],
+    })
+}
+
+fn group_list(names: &[&str]) -> Value {
+    json!({
+        "count": names.len(),
+        "results": names.iter().map(|name| json!({ "name": name, "url": "" })).collect::<Vec<_>>(),
+    })
+}
+
+fn species_group(name: &str, species: &[&str]) -> Value {
+    json!({
+        "name": name,
+        "pokemon_species": species.iter().map(|name| json!({ "name": name, "url": "" })).collect::<Vec<_>>(),
+    })
+}
+
+/// Real damage relations for the types the tests look at; the others deal
+/// normal damage to everything.
+fn type_fixture(name: &str) -> Value {
+    let (double, half, none): (&[&str], &[&str], &[&str]) = match name {
+        "electric" => (
+            &["flying", "water"],
+            &["electric", "grass", "dragon"],
+            &["ground"],
+        ),
+        "ground" => (
+            &["electric", "fire", "poison", "rock", "steel"],
+            &["bug", "grass"],
+            &["flying"],
+        ),
+        "flying" => (
+            &["fighting", "bug", "grass"],
+            &["electric", "rock", "steel"],
+            &[],
+        ),
+        "steel" => (
+            &["ice", "rock", "fairy"],
+            &["fire", "water", "electric", "steel"],
+            &[],
+        ),
+        _ => (&[], &[], &[]),
+    };
+    let resources = |types: &[&str]| {
+        types
+            .iter()
+            .map(|name| json!({ "name": name, "url": "" }))
+            .collect::<Vec<_>>()
+    };
+
+    json!({
+        "name": name,
+        "damage_relations": {
+            "double_damage_to": resources(double),
+            "half_damage_to": resources(half),
+            "no_damage_to": resources(none),
+            "double_damage_from": [],
+            "half_damage_from": [],
+            "no_damage_from": [],
+        },
+    })
+}
+
+fn evolution_chain_fixture() -> Value {
+    let resource = |name: &str| json!({ "name": name, "url": "" });
+    let link = |name: &str, is_baby: bool, details: Value, evolves_to: Value| {
+        json!({
+            "species": resource(name),
+            "is_baby": is_baby,
+            "evolution_details": details,
+            "evolves_to": evolves_to,
+        })
+    };
+
+    json!({
+        "id": 10,
+        "baby_trigger_item": null,
+        "chain": link("pichu", true, json!([]), json!([
+            link("pikachu", false, json!([{
+                "trigger": resource("level-up"),
+                "min_happiness": 220,
+                "time_of_day": "",
+                "needs_overworld_rain": false,
+                "turn_upside_down": false,
+            }]), json!([
+                link("raichu", false, json!([{
+                    "trigger": resource("use-item"),
+                    "item": resource("thunder-stone"),
+                    "time_of_day": "",
+                }]), json!([])),
+            ])),
+        ])),
+    })
+}
+
+struct UpstreamStub {
+    base_url: String,
+    translate_calls: Arc<AtomicUsize>,
+    evolution_calls: Arc<AtomicUsize>,
+    request_ids: Arc<Mutex<Vec<String>>>,
+}
+
+async fn spawn_upstream_stub() -> UpstreamStub {
+    serve_upstream_stub(tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap())
+}
+
+fn serve_upstream_stub(listener: tokio::net::TcpListener) -> UpstreamStub {
+    let flaky_calls = Arc::new(AtomicUsize::new(0));
+    let translate_calls = Arc::new(AtomicUsize::new(0));
+    let translate_calls_counter = translate_calls.clone();
+    let evolution_calls = Arc::new(AtomicUsize::new(0));
+    let evolution_calls_counter = evolution_calls.clone();
+    let request_ids = Arc::new(Mutex::new(Vec::new()));
+    let request_ids_seen = request_ids.clone();
+
+    let router = Router::new()
+        .route(
+            "/pokemon-species/{name}/",
+            get(move |Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "pikachu" | "25" => Ok(Json(species_fixture("pikachu", "forest", false))),
+                    "pichu" | "raichu" => Ok(Json(species_fixture(&name, "forest", false))),
+                    "nidoran-f" => Ok(Json(species_fixture("nidoran-f", "grassland", false))),
+                    "giratina" => {
+                        let mut species = species_fixture("giratina", "rare", true);
+                        species["varieties"] = json!([
+                            { "is_default": true, "pokemon": { "name": "giratina-altered", "url": "" } },
+                            { "is_default": false, "pokemon": { "name": "giratina-origin", "url": "" } },
+                        ]);
+                        Ok(Json(species))
+                    }
+                    "porygon" if flaky_calls.fetch_add(1, Ordering::SeqCst) == 0 => {
+                        Err(StatusCode::SERVICE_UNAVAILABLE)
+                    }
+                    "porygon" => Ok(Json(species_fixture("porygon", "urban", false))),
+                    "broken" => Err(StatusCode::INTERNAL_SERVER_ERROR),
+                    "mewtwo" => Ok(Json(species_fixture("mewtwo", "rare", true))),
+                    "ditto" => {
+                        let mut species = species_fixture("ditto", "urban", false);
+                        species["flavor_text_entries"][0]["flavor_text"] = json!("rate-limited");
+                        Ok(Json(species))
+                    }
+                    "magikarp" => {
+                        let mut species = species_fixture("magikarp", "waters-edge", false);
+                        species["flavor_text_entries"][0]["flavor_text"] = json!("server-error");
+                        Ok(Json(species))
+                    }
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/pokemon/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "pikachu" => Ok(Json(pokemon_fixture("pikachu", "pikachu"))),
+                    "giratina-origin" => Ok(Json(pokemon_fixture(&name, "giratina"))),
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/pokemon-species/",
+            get(|| async {
+                let species = [
+                    ("pichu", 172),
+                    ("pikachu", 25),
+                    ("raichu", 26),
+                    ("giratina", 487),
+                    ("mewtwo", 150),
+                ];
+                Json(json!({
+                    "count": species.len(),
+                    "results": species
+                        .iter()
+                        .map(|(name, id)| json!({
+                            "name": name,
+                            "url": format!("https://pokeapi.co/api/v2/pokemon-species/{}/", id),
+                        }))
+                        .collect::<Vec<_>>(),
+                }))
+            }),
+        )
+        .route("/generation/", get(|| async { Json(group_list(&["generation-i", "generation-ii", "generation-iv"])) }))
+        .route(
+            "/generation/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "generation-i" => Json(species_group(&name, &["pikachu", "raichu", "mewtwo"])),
+                    "generation-ii" => Json(species_group(&name, &["pichu"])),
+                    _ => Json(species_group(&name, &["giratina"])),
+                }
+            }),
+        )
+        .route("/pokemon-habitat/", get(|| async { Json(group_list(&["forest", "rare"])) }))
+        .route(
+            "/pokemon-habitat/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "forest" => Json(species_group(&name, &["pikachu", "raichu", "pichu"])),
+                    _ => Json(species_group(&name, &["mewtwo", "giratina"])),
+                }
+            }),
+        )
+        .route("/pokemon-color/", get(|| async { Json(group_list(&["yellow", "purple", "black"])) }))
+        .route(
+            "/pokemon-color/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "yellow" => Json(species_group(&name, &["pikachu", "raichu", "pichu"])),
+                    "purple" => Json(species_group(&name, &["mewtwo"])),
+                    _ => Json(species_group(&name, &["giratina"])),
+                }
+            }),
+        )
+        .route(
+            "/pokedex/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "original-johto" => Ok(Json(json!({
+                        "name": name,
+                        "pokemon_entries": [
+                            { "entry_number": 22, "pokemon_species": { "name": "pikachu", "url": "" } },
+                        ],
+                    }))),
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/ability/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "static" => Ok(Json(ability_fixture())),
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/move/{name}/",
+            get(|Path(name): Path<String>| async move {
+                match name.as_str() {
+                    "thunderbolt" => Ok(Json(move_fixture())),
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/type/{name}/",
+            get(|Path(name): Path<String>| async move { Json(type_fixture(&name)) }),
+        )
+        .route(
+            "/evolution-chain/{id}/",
+            get(move |Path(id): Path<u32>| async move {
+                evolution_calls_counter.fetch_add(1, Ordering::SeqCst);
+                match id {
+                    10 => Ok(Json(evolution_chain_fixture())),
+                    _ => Err(StatusCode::NOT_FOUND),
+                }
+            }),
+        )
+        .route(
+            "/translate/{style}",
+            post(
+                move |Path(style): Path<String>, Json(body): Json<Value>| async move {
+                    translate_calls_counter.fetch_add(1, Ordering::SeqCst);
+
+                    if body["text"] == "rate-limited" {
+                        return Err(StatusCode::TOO_MANY_REQUESTS);
+                    }
+                    if body["text"] == "server-error" {
+                        return Err(StatusCode::SERVICE_UNAVAILABLE);
+                    }
+
+                    let translated = format!("{} says stub", style.trim_end_matches(".json"));
+                    Ok(Json(json!({
+                        "success": { "total": 1 },
+                        "contents": {
+                            "translated": translated,
+                            "text": "A stub description.",
+                            "translation": style,
+                        }
+                    })))
+                },
+            ),
+        )
+        .layer(middleware::from_fn(
+            move |request: StubRequest, next: Next| {
+                let request_ids = request_ids_seen.clone();
+                async move {
+                    if let Some(id) = request.headers().get("x-request-id") {
+                        request_ids
+                            .lock()
+                            .unwrap()
+                            .push(id.to_str().unwrap().to_string());
+                    }
+                    next.run(request).await
+                }
+            },
+        ));
+
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    UpstreamStub {
+        base_url: format!("http://{}", addr),
+        translate_calls,
+        evolution_calls,
+        request_ids,
+    }
+}
+
+fn config_against(base_url: &str) -> AppConfig {
+    AppConfig {
+        enable_swagger: false,
+        pokeapi_base_url: base_url.to_string(),
+        funtranslations_base_url: base_url.to_string(),
+        upstream_retry_base_delay_ms: 10,
+        ..AppConfig::default()
+    }
+}
+
+async fn app_against(base_url: &str) -> Router {
+    create_app(init_app_state(config_against(base_url)).await.unwrap())
+}
+
+/// An app whose species index is built, as it is shortly after startup.
+async fn warm_app_against(base_url: &str) -> Router {
+    let state = init_app_state(config_against(base_url)).await.unwrap();
+    state
+        .pokemon_use_cases
+        .refresh_species_index()
+        .await
+        .unwrap();
+    create_app(state)
+}
+
+async fn get_json(app: Router, uri: &str) -> (StatusCode, Value) {
+    let (status, _, body) = send(app, Request::get(uri).body(Body::empty()).unwrap()).await;
+    (status, body)
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, HeaderMap, Value) {
+    let response = app.oneshot(request).await.unwrap();
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+    (status, headers, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_get_pokemon_uses_configured_pokeapi_base_url() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/pikachu").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(body["description"], "A stub description.");
+    assert_eq!(body["habitat"], "Forest");
+    assert_eq!(body["id"], 1);
+    assert_eq!(body["generation"], "generation-i");
+    assert_eq!(body["genus"], "Stub Pokémon");
+    assert_eq!(body["growthRate"], "medium");
+    assert_eq!(body["captureRate"], 45);
+    assert_eq!(body["eggGroups"], json!(["ground", "fairy"]));
+    assert_eq!(body["varieties"][0]["name"], "pikachu");
+    assert!(body.get("translation").is_none());
+}
+
+#[tokio::test]
+async fn test_get_pokemon_with_includes_merges_pokemon_endpoint() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?include=types,stats,abilities,size,sprites",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "A stub description.");
+    assert_eq!(body["types"], json!(["electric"]));
+    assert_eq!(body["stats"]["speed"], 90);
+    assert_eq!(body["stats"]["total"], 320);
+    assert_eq!(body["abilities"][1]["name"], "lightning-rod");
+    assert_eq!(body["abilities"][1]["isHidden"], true);
+    assert_eq!(body["height"], 4);
+    assert_eq!(body["weight"], 60);
+    assert_eq!(body["sprites"]["frontDefault"], "https://img/pikachu.png");
+}
+
+#[tokio::test]
+async fn test_get_pokemon_includes_only_requested_sections() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?include=types",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["types"], json!(["electric"]));
+    assert!(body.get("stats").is_none());
+    assert!(body.get("sprites").is_none());
+}
+
+#[tokio::test]
+async fn test_get_pokemon_with_unknown_include_is_a_bad_request() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?include=evolutions",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "BAD_REQUEST");
+}
+
+#[tokio::test]
+async fn test_get_evolutions_returns_nested_chain() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    let (status, body) = get_json(app.clone(), "/pokemon/pikachu/evolutions").await;
+    let (_, cached) = get_json(app, "/pokemon/pikachu/evolutions").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], 10);
+    assert_eq!(body["chain"]["species"], "pichu");
+    assert_eq!(body["chain"]["isBaby"], true);
+    assert_eq!(body["chain"]["conditions"], json!([]));
+
+    let pikachu = &body["chain"]["evolvesTo"][0];
+    assert_eq!(pikachu["species"], "pikachu");
+    assert_eq!(
+        pikachu["conditions"],
+        json!([{ "trigger": "level-up", "minHappiness": 220 }])
+    );
+
+    let raichu = &pikachu["evolvesTo"][0];
+    assert_eq!(raichu["conditions"][0]["trigger"], "use-item");
+    assert_eq!(raichu["conditions"][0]["item"], "thunder-stone");
+    assert_eq!(raichu["evolvesTo"], json!([]));
+
+    assert_eq!(cached, body);
+    assert_eq!(stub.evolution_calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_get_pokemon_honors_accept_language() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, headers, body) = send(
+        app_against(&stub.base_url).await,
+        Request::get("/pokemon/pikachu")
+            .header(header::ACCEPT_LANGUAGE, "de, fr-CH;q=0.9, en;q=0.5")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::CONTENT_LANGUAGE], "fr");
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(body["displayName"], "Bouchon");
+    assert_eq!(body["genus"], "Pokémon Bouchon");
+    assert_eq!(body["description"], "Une description bouchon.");
+}
+
+#[tokio::test]
+async fn test_get_pokemon_lang_param_overrides_header_and_falls_back_to_english() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, headers, body) = send(
+        app_against(&stub.base_url).await,
+        Request::get("/pokemon/pikachu?lang=ko")
+            .header(header::ACCEPT_LANGUAGE, "fr")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::CONTENT_LANGUAGE], "en");
+    assert_eq!(body["displayName"], "Stub");
+    assert_eq!(body["description"], "A stub description.");
+}
+
+#[tokio::test]
+async fn test_get_pokemon_with_version_selects_that_flavor_text() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?version=yellow",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "A yellow description.");
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?version=x&lang=fr",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "Une description bouchon.");
+}
+
+#[tokio::test]
+async fn test_get_pokemon_with_unknown_version_returns_not_found() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?version=sword",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_get_flavor_texts_groups_identical_texts() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu/flavor-texts",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(
+        body["flavorTexts"],
+        json!([
+            { "language": "en", "text": "A stub description.", "versions": ["red", "blue"] },
+            { "language": "fr", "text": "Une description bouchon.", "versions": ["x"] },
+            { "language": "en", "text": "A yellow description.", "versions": ["yellow"] },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_get_pokemon_by_national_dex_number() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/25").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(
+        body["variety"],
+        json!({ "name": "pikachu", "isDefault": true })
+    );
+    assert_eq!(
+        body["pokedexNumbers"],
+        json!([
+            { "pokedex": "national", "entryNumber": 25 },
+            { "pokedex": "original-johto", "entryNumber": 22 },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_get_pokemon_by_regional_dex_number() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/22?pokedex=original-johto&include=types",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(body["types"], json!(["electric"]));
+
+    let (status, _) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/999?pokedex=original-johto",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu?pokedex=original-johto",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_pokemon_by_variety_name_resolves_species() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/giratina-origin?include=size",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "giratina");
+    assert_eq!(
+        body["variety"],
+        json!({ "name": "giratina-origin", "isDefault": false })
+    );
+    assert_eq!(body["height"], 4);
+}
+
+#[tokio::test]
+async fn test_misspelled_pokemon_returns_suggestions() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) =
+        get_json(warm_app_against(&stub.base_url).await, "/pokemon/pikachuu").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+    assert_eq!(body["error"]["suggestions"], json!(["pikachu"]));
+}
+
+#[tokio::test]
+async fn test_cold_species_index_does_not_hold_up_requests() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    let (status, body) = get_json(app.clone(), "/pokemon/pikachuu").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"].get("suggestions"), None);
+
+    let (status, body) = get_json(app, "/pokemon/search?q=pi").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["error"]["code"], "SERVICE_UNAVAILABLE");
+}
+
+#[tokio::test]
+async fn test_search_matches_prefixes_and_localized_names() {
+    let stub = spawn_upstream_stub().await;
+    let app = warm_app_against(&stub.base_url).await;
+
+    let (status, body) = get_json(app.clone(), "/pokemon/search?q=pi").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["results"][0]["name"], "pichu");
+    assert_eq!(body["results"][1]["name"], "pikachu");
+
+    // Every stub species is called `Bouchon` in French.
+    let (_, body) = get_json(app.clone(), "/pokemon/search?q=bouch&limit=2").await;
+    assert_eq!(
+        body["results"],
+        json!([
+            { "name": "giratina", "matchedName": "Bouchon", "language": "fr" },
+            { "name": "mewtwo", "matchedName": "Bouchon", "language": "fr" },
+        ])
+    );
+
+    let (status, _) = get_json(app, "/pokemon/search").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_pokemon_names_are_normalized_to_pokeapi_slugs() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/PIKACHU").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/Nidoran%E2%99%80",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "nidoran-f");
+}
+
+#[tokio::test]
+async fn test_invalid_pokemon_names_are_rejected_before_any_upstream_call() {
+    let stub = spawn_upstream_stub().await;
+
+    for uri in [
+        "/pokemon/pika%2Fchu",
+        "/pokemon/%20",
+        "/pokemon/translated/pika%3Fchu",
+        "/pokemon/pika%2Fchu/evolutions",
+        "/pokemon/25?pokedex=..%2Fpokemon%2Fditto",
+    ] {
+        let (status, body) = get_json(app_against(&stub.base_url).await, uri).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        assert_eq!(body["error"]["code"], "BAD_REQUEST");
+    }
+    assert_eq!(stub.translate_calls.load(Ordering::SeqCst), 0);
+    assert_eq!(stub.evolution_calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_list_pokemon_pages_through_species_by_dex_number() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon?limit=2").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["count"], 5);
+    assert_eq!(body["next"], "/pokemon?limit=2&offset=2");
+    assert_eq!(body["prev"], Value::Null);
+    assert_eq!(
+        body["results"],
+        json!([
+            { "id": 25, "name": "pikachu", "generation": "generation-i", "habitat": "forest", "color": "yellow", "url": "/pokemon/pikachu" },
+            { "id": 26, "name": "raichu", "generation": "generation-i", "habitat": "forest", "color": "yellow", "url": "/pokemon/raichu" },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_list_pokemon_filters_and_keeps_filters_in_links() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon?habitat=Forest&limit=1&offset=1",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["count"], 3);
+    assert_eq!(body["results"][0]["name"], "raichu");
+    assert_eq!(body["prev"], "/pokemon?limit=1&offset=0&habitat=Forest");
+    assert_eq!(body["next"], "/pokemon?limit=1&offset=2&habitat=Forest");
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon?legendary=true&color=purple",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["count"], 1);
+    assert_eq!(body["results"][0]["name"], "mewtwo");
+
+    let (status, _) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon?generation=gen-1",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_batch_returns_a_result_per_name() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, _, body) = send(
+        app_against(&stub.base_url).await,
+        Request::post("/pokemon/batch")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({ "names": ["pikachu", "missingno", "pika/chu"] }).to_string(),
+            ))
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["status"], 200);
+    assert_eq!(results[0]["pokemon"]["name"], "pikachu");
+    assert_eq!(results[1]["name"], "missingno");
+    assert_eq!(results[1]["status"], 404);
+    assert_eq!(results[1]["error"]["code"], "NOT_FOUND");
+    assert_eq!(results[2]["status"], 400);
+    assert_eq!(results[2]["error"]["code"], "BAD_REQUEST");
+}
+
+#[tokio::test]
+async fn test_translated_batch_translates_each_pokemon() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, _, body) = send(
+        app_against(&stub.base_url).await,
+        Request::post("/pokemon/batch")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({ "names": ["mewtwo", "pikachu"], "translated": true }).to_string(),
+            ))
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["results"][0]["pokemon"]["description"],
+        "yoda says stub"
+    );
+    assert_eq!(
+        body["results"][1]["pokemon"]["translation"]["style"],
+        "shakespeare"
+    );
+}
+
+#[tokio::test]
+async fn test_empty_or_oversized_batch_is_a_bad_request() {
+    let stub = spawn_upstream_stub().await;
+
+    for names in [json!([]), json!(vec!["pikachu"; 21])] {
+        let (status, _, body) = send(
+            app_against(&stub.base_url).await,
+            Request::post("/pokemon/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "names": names }).to_string()))
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "BAD_REQUEST");
+    }
+}
+
+#[tokio::test]
+async fn test_type_matchups_come_from_the_type_chart() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/types/Electric/matchups",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "electric");
+    assert_eq!(
+        body["attacking"],
+        json!([
+            { "type": "flying", "multiplier": 2.0 },
+            { "type": "ground", "multiplier": 0.0 },
+            { "type": "water", "multiplier": 2.0 },
+            { "type": "grass", "multiplier": 0.5 },
+            { "type": "electric", "multiplier": 0.5 },
+            { "type": "dragon", "multiplier": 0.5 },
+        ])
+    );
+    assert_eq!(
+        body["defending"],
+        json!([
+            { "type": "flying", "multiplier": 0.5 },
+            { "type": "ground", "multiplier": 2.0 },
+            { "type": "steel", "multiplier": 0.5 },
+            { "type": "electric", "multiplier": 0.5 },
+        ])
+    );
+
+    let (status, body) =
+        get_json(app_against(&stub.base_url).await, "/types/shadow/matchups").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_pokemon_weaknesses_follow_its_types() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu/weaknesses",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(body["types"], json!(["electric"]));
+    assert_eq!(
+        body["weaknesses"],
+        json!([{ "type": "ground", "multiplier": 2.0 }])
+    );
+    assert_eq!(body["resistances"].as_array().unwrap().len(), 3);
+    assert_eq!(body["immunities"], json!([]));
+}
+
+#[tokio::test]
+async fn test_get_ability_selects_the_effect_language() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/abilities/Static?lang=de",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "static");
+    assert_eq!(
+        body["effect"],
+        json!({ "language": "de", "effect": "Paralysiert bei Kontakt.", "shortEffect": "Kann paralysieren." })
+    );
+    assert_eq!(
+        body["pokemon"],
+        json!([
+            { "name": "pikachu", "isHidden": false },
+            { "name": "electrode", "isHidden": true },
+        ])
+    );
+
+    let (status, _) = get_json(app_against(&stub.base_url).await, "/abilities/drizzle").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_move_fills_in_the_effect_chance() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/moves/thunderbolt").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["type"], "electric");
+    assert_eq!(body["damageClass"], "special");
+    assert_eq!(body["power"], 90);
+    assert_eq!(body["accuracy"], 100);
+    assert_eq!(body["pp"], 15);
+    assert_eq!(body["effectChance"], 10);
+    assert_eq!(
+        body["effect"]["shortEffect"],
+        "Has a 10% chance to paralyze."
+    );
+
+    let (status, _) = get_json(app_against(&stub.base_url).await, "/moves/thunder%2Fbolt").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_pokemon_moves_filter_by_version_group_and_method() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu/moves?version_group=red-blue&method=level-up",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "pikachu");
+    assert_eq!(
+        body["moves"],
+        json!([
+            { "name": "thunder-shock", "method": "level-up", "level": 1, "versionGroup": "red-blue" },
+            { "name": "thunderbolt", "method": "level-up", "level": 26, "versionGroup": "red-blue" },
+        ])
+    );
+
+    let (_, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/pikachu/moves?method=machine",
+    )
+    .await;
+    assert_eq!(
+        body["moves"],
+        json!([{ "name": "thunderbolt", "method": "machine", "versionGroup": "red-blue" }])
+    );
+}
+
+#[tokio::test]
+async fn test_metrics_cover_routes_caches_and_upstream_calls() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    get_json(app.clone(), "/pokemon/pikachu").await;
+    get_json(app.clone(), "/pokemon/pikachu").await;
+    get_json(app.clone(), "/pokemon/translated/mewtwo").await;
+    app.clone()
+        .oneshot(Request::get("/no/such/route").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let response = app
+        .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain; version=0.0.4")
+    );
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let metrics = String::from_utf8(bytes.to_vec()).unwrap();
+
+    for line in [
+        r#"http_requests_total{method="GET",route="/pokemon/{pokemon_name}",status="200"} 2"#,
+        r#"http_requests_total{method="GET",route="unmatched",status="404"} 1"#,
+        r#"cache_requests_total{cache="pokemon",result="hit"} 1"#,
+        r#"cache_requests_total{cache="translations",result="miss"} 1"#,
+        r#"upstream_requests_total{client="pokeapi",endpoint="pokemon-species",outcome="success"} 2"#,
+        r#"upstream_requests_total{client="funtranslations",endpoint="yoda",outcome="success"} 1"#,
+    ] {
+        assert!(metrics.contains(line), "missing {}\n{}", line, metrics);
+    }
+    assert!(metrics.contains("http_request_duration_seconds_bucket"));
+    assert!(metrics.contains("upstream_request_duration_seconds_bucket"));
+}
+
+#[tokio::test]
+async fn test_get_pokemon_unknown_species_returns_not_found() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/missingno").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_get_translated_pokemon_uses_configured_funtranslations_base_url() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/translated/mewtwo",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "yoda says stub");
+    assert_eq!(body["translation"]["style"], "yoda");
+    assert_eq!(body["translation"]["fromCache"], false);
+    assert!(body["translation"]["fallbackReason"].is_null());
+    assert_eq!(
+        body["translation"]["originalDescription"],
+        "A stub description."
+    );
+}
+
+#[tokio::test]
+async fn test_get_translated_pokemon_with_explicit_style() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/translated/mewtwo?style=pirate",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "pirate says stub");
+}
+
+#[tokio::test]
+async fn test_type_rule_from_policy_file_sees_the_pokemon_types() {
+    let stub = spawn_upstream_stub().await;
+    let policy_path = std::env::temp_dir().join(format!(
+        "simple_pokedex_type_policy_{}.json",
+        std::process::id()
+    ));
+    std::fs::write(
+        &policy_path,
+        r#"{ "default": "shakespeare", "rules": [{ "when": { "type": "electric" }, "translator": "pirate" }] }"#,
+    )
+    .unwrap();
+    let config = AppConfig {
+        translation_policy_file: Some(policy_path.to_string_lossy().into_owned()),
+        ..config_against(&stub.base_url)
+    };
+    let app = create_app(init_app_state(config).await.unwrap());
+
+    let (status, body) = get_json(app, "/pokemon/translated/pikachu").await;
+    let _ = std::fs::remove_file(&policy_path);
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "pirate says stub");
+}
+
+#[tokio::test]
+async fn test_get_translated_pokemon_with_unknown_style_is_a_bad_request() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/translated/mewtwo?style=elvish",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "BAD_REQUEST");
+    assert_eq!(stub.translate_calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_transient_upstream_failure_is_retried() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/porygon").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "porygon");
+}
+
+#[tokio::test]
+async fn test_persistent_upstream_failure_is_an_internal_error() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(app_against(&stub.base_url).await, "/pokemon/broken").await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["error"]["code"], "INTERNAL_ERROR");
+}
+
+#[tokio::test]
+async fn test_failed_translations_are_not_retried() {
+    let stub = spawn_upstream_stub().await;
+
+    let (status, body) = get_json(
+        app_against(&stub.base_url).await,
+        "/pokemon/translated/magikarp",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "server-error");
+    assert_eq!(stub.translate_calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_refused_translation_connections_are_retried() {
+    let stub = spawn_upstream_stub().await;
+
+    // Nothing listens on `addr` for the first attempt, so it is refused
+    // before the POST is sent and retrying it cannot spend quota twice.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let late_stub = tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        serve_upstream_stub(tokio::net::TcpListener::bind(addr).await.unwrap())
+    });
+    let config = AppConfig {
+        funtranslations_base_url: format!("http://{}", addr),
+        upstream_retry_base_delay_ms: 300,
+        ..config_against(&stub.base_url)
+    };
+
+    let (status, body) = get_json(
+        create_app(init_app_state(config).await.unwrap()),
+        "/pokemon/translated/mewtwo",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "yoda says stub");
+    let late_stub = late_stub.await.unwrap();
+    assert_eq!(late_stub.translate_calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_rate_limited_translations_open_the_circuit() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    for expected_reason in [
+        "rate_limited",
+        "rate_limited",
+        "rate_limited",
+        "service_unavailable",
+    ] {
+        let (status, body) = get_json(app.clone(), "/pokemon/translated/ditto").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["description"], "rate-limited");
+        assert_eq!(body["translation"]["fallbackReason"], expected_reason);
+    }
+
+    assert_eq!(stub.translate_calls.load(Ordering::SeqCst), 3);
+
+    let (status, body) = get_json(app, "/health").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["circuitBreakers"]["funTranslations"], "open");
+}
+
+#[tokio::test]
+async fn test_stale_translation_is_served_and_refreshed_in_background() {
+    let stub = spawn_upstream_stub().await;
+    let config = AppConfig {
+        translation_cache_soft_ttl_secs: 0,
+        ..config_against(&stub.base_url)
+    };
+    let app = create_app(init_app_state(config).await.unwrap());
+
+    let (_, first) = get_json(app.clone(), "/pokemon/translated/pikachu").await;
+    let (_, second) = get_json(app, "/pokemon/translated/pikachu").await;
+
+    assert_eq!(first["description"], "shakespeare says stub");
+    assert_eq!(second["description"], "shakespeare says stub");
+    assert_eq!(first["translation"]["fromCache"], false);
+    assert_eq!(second["translation"]["fromCache"], true);
+
+    for _ in 0..50 {
+        if stub.translate_calls.load(Ordering::SeqCst) == 2 {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    panic!("stale translation was not refreshed in the background");
+}
+
+#[tokio::test]
+async fn test_request_id_is_echoed_and_forwarded_upstream() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    let request = Request::get("/pokemon/translated/mewtwo")
+        .header("x-request-id", "trace-me-42")
+        .body(Body::empty())
+        .unwrap();
+    let (status, headers, _) = send(app, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-request-id"], "trace-me-42");
+    let seen = stub.request_ids.lock().unwrap().clone();
+    assert_eq!(seen, ["trace-me-42", "trace-me-42"]);
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_and_returned_in_error_bodies() {
+    let stub = spawn_upstream_stub().await;
+    let app = app_against(&stub.base_url).await;
+
+    let request = Request::get("/pokemon/missingno")
+        .header("x-request-id", "not a valid id")
+        .body(Body::empty())
+        .unwrap();
+    let (status, headers, body) = send(app, request).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let request_id = headers["x-request-id"].to_str().unwrap();
+    assert_eq!(request_id.len(), 36);
+    assert_eq!(body["error"]["request_id"], request_id);
+}